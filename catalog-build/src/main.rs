@@ -0,0 +1,167 @@
+//! Rebuild `src/musicals.csv` from saved Wikipedia "List of musicals" pages,
+//! keeping the ids of known musicals. To be run from the repository root:
+//!
+//! ```sh
+//! curl -o a_to_l.html https://en.wikipedia.org/wiki/List_of_musicals:_A_to_L
+//! curl -o m_to_z.html https://en.wikipedia.org/wiki/List_of_musicals:_M_to_Z
+//! cargo run -p catalog-build -- a_to_l.html m_to_z.html
+//! ```
+//!
+//! Also updates `src/musical_remap.csv` with the ids of musicals which are
+//! gone, and writes what was dropped to `src/catalog_dropped.csv`.
+use musicalist::catalog::{self, Dropped, IdConflict, Row};
+use musicalist::model::Musical;
+use musicalist::musicals::{parse_musicals, parse_remap, Remap};
+use std::collections::HashMap;
+use std::env;
+use std::error::Error;
+use std::fs;
+use std::process::ExitCode;
+
+mod wikipedia;
+
+const MUSICALS_PATH: &str = "src/musicals.csv";
+const REMAP_PATH: &str = "src/musical_remap.csv";
+const EXCLUDED_PATH: &str = "src/catalog_excluded.csv";
+const DROPPED_PATH: &str = "src/catalog_dropped.csv";
+
+/// Build the next catalog from the scraped `rows`, updating `remap`.
+fn build(
+    previous: &[Musical],
+    remap: &mut Remap,
+    excluded: &HashMap<String, String>,
+    rows: Vec<Row>,
+) -> Result<(Vec<Musical>, Vec<Dropped>), Vec<IdConflict>> {
+    let previous: Vec<Musical> = previous
+        .iter()
+        .map(|musical| {
+            let (name, url) = catalog::normalize(&musical.name, &musical.url);
+            Musical {
+                name,
+                url,
+                ..musical.clone()
+            }
+        })
+        .collect();
+    let (rows, dropped) = catalog::clean(rows, excluded);
+    let mut next = catalog::assign_ids(&previous, remap, rows);
+    catalog::label_duplicates(&mut next);
+    catalog::retire_dropped(&previous, &next, remap);
+    catalog::check_ids(&previous, &next, remap)?;
+    Ok((next, dropped))
+}
+
+fn run() -> Result<ExitCode, Box<dyn Error>> {
+    let pages: Vec<String> = env::args().skip(1).collect();
+    if pages.is_empty() {
+        eprintln!("usage: catalog-build <saved list of musicals page>...");
+        return Ok(ExitCode::FAILURE);
+    }
+
+    let previous = parse_musicals(&fs::read_to_string(MUSICALS_PATH)?);
+    let mut remap = parse_remap(&fs::read_to_string(REMAP_PATH)?);
+    let excluded = catalog::parse_excluded(&fs::read_to_string(EXCLUDED_PATH)?)?;
+
+    let mut rows = vec![];
+    for page in pages {
+        rows.extend(wikipedia::extract_rows(&fs::read_to_string(page)?));
+    }
+
+    let (next, dropped) = match build(&previous, &mut remap, &excluded, rows) {
+        Ok(build) => build,
+        Err(conflicts) => {
+            for conflict in conflicts {
+                eprintln!("{}", conflict);
+            }
+            return Ok(ExitCode::FAILURE);
+        }
+    };
+
+    fs::write(MUSICALS_PATH, catalog::write_musicals(&next)?)?;
+    fs::write(REMAP_PATH, catalog::write_remap(&remap)?)?;
+    fs::write(DROPPED_PATH, catalog::write_dropped(&dropped)?)?;
+    eprintln!("{} musicals, {} rows dropped", next.len(), dropped.len());
+    Ok(ExitCode::SUCCESS)
+}
+
+fn main() -> ExitCode {
+    run().unwrap_or_else(|err| {
+        eprintln!("{}", err);
+        ExitCode::FAILURE
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fixture_rows() -> Vec<Row> {
+        let mut rows = wikipedia::extract_rows(include_str!(
+            "../tests/fixtures/list_of_musicals_a_to_l.html"
+        ));
+        rows.extend(wikipedia::extract_rows(include_str!(
+            "../tests/fixtures/list_of_musicals_m_to_z.html"
+        )));
+        rows
+    }
+
+    fn musical(id: u64, name: &str, url: &str) -> Musical {
+        Musical {
+            id,
+            name: name.to_string(),
+            url: url.to_string(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn builds_the_catalog_from_fixtures() {
+        let previous = vec![
+            musical(1, "101 Dalmatians", "101 Dalmatians (2022 musical)"),
+            musical(2, "Dodie Smith", "Dodie Smith"),
+            musical(7, "Miss Saigon", "Miss Saigon"),
+            musical(
+                11,
+                "35mm: A Musical Exhibition",
+                "35mm: A Musical Exhibition (page does not exist)",
+            ),
+        ];
+        let mut remap = Remap::from([(12, Some(7))]);
+        let (next, dropped) =
+            build(&previous, &mut remap, &HashMap::new(), fixture_rows()).unwrap();
+        let next: Vec<_> = next.iter().map(|m| (m.id, m.display_name())).collect();
+        assert_eq!(
+            next,
+            vec![
+                (1, "101 Dalmatians".to_string()),
+                (11, "35mm: A Musical Exhibition".to_string()),
+                (13, "42nd Street (film)".to_string()),
+                (14, "42nd Street (musical)".to_string()),
+                (15, "Ain't Misbehavin'".to_string()),
+                (16, "Aladdin".to_string()),
+                (17, "Anne & Gilbert".to_string()),
+                (18, "Les Misérables".to_string()),
+                (7, "Miss Saigon".to_string()),
+                (19, "Ziegfeld Follies of 1909".to_string()),
+                (20, "Zombie Prom".to_string()),
+            ]
+        );
+        let dropped: Vec<_> = dropped.iter().map(|d| d.name.as_str()).collect();
+        assert_eq!(dropped, vec!["Annie Get Your Gun", "Les Misérables"]);
+        assert_eq!(remap, Remap::from([(2, None), (12, Some(7))]));
+    }
+
+    #[test]
+    fn refuses_ids_listed_in_the_remap_table() {
+        let previous = vec![musical(1, "Miss Saigon", "Miss Saigon")];
+        let rows = vec![Row::new("Miss Saigon", "Miss Saigon")];
+        let mut remap = Remap::from([(1, Some(2))]);
+        assert_eq!(
+            build(&previous, &mut remap, &HashMap::new(), rows),
+            Err(vec![IdConflict::Remapped {
+                id: 1,
+                now: "Miss Saigon (Miss Saigon)".to_string()
+            }])
+        );
+    }
+}
@@ -0,0 +1,195 @@
+//! Extraction of the musicals listed in a saved "List of musicals" page.
+use musicalist::catalog::Row;
+use scraper::{ElementRef, Html, Selector};
+
+fn selector(selectors: &str) -> Selector {
+    Selector::parse(selectors).unwrap()
+}
+
+fn child_elements<'a>(
+    element: ElementRef<'a>,
+    name: &'a str,
+) -> impl Iterator<Item = ElementRef<'a>> {
+    element
+        .children()
+        .filter_map(ElementRef::wrap)
+        .filter(move |child| child.value().name() == name)
+}
+
+fn cells(row: ElementRef) -> Vec<ElementRef> {
+    row.children()
+        .filter_map(ElementRef::wrap)
+        .filter(|cell| matches!(cell.value().name(), "td" | "th"))
+        .collect()
+}
+
+/// Text of a cell without its references, `None` for blank cells.
+fn cell_text(cell: ElementRef) -> Option<String> {
+    let text: String = cell
+        .descendants()
+        .filter(|node| {
+            !node.ancestors().any(|ancestor| {
+                ancestor
+                    .value()
+                    .as_element()
+                    .is_some_and(|element| element.name() == "sup")
+            })
+        })
+        .filter_map(|node| node.value().as_text().map(|text| text.to_string()))
+        .collect();
+    let text = text.split_whitespace().collect::<Vec<_>>().join(" ");
+    match text.as_str() {
+        "" | "-" | "–" | "—" => None,
+        _ => Some(text),
+    }
+}
+
+/// First four digit number of a cell, e.g. 1933 for "1933 (film)".
+fn year(text: &str) -> Option<u16> {
+    text.split(|c: char| !c.is_ascii_digit())
+        .find(|digits| digits.len() == 4)
+        .and_then(|digits| digits.parse().ok())
+}
+
+/// Position of the metadata columns of a table, from its header row.
+#[derive(Default)]
+struct Columns {
+    year: Option<usize>,
+    composer: Option<usize>,
+    lyricist: Option<usize>,
+    book_writer: Option<usize>,
+}
+
+impl Columns {
+    fn from_header(header: &[ElementRef]) -> Columns {
+        let mut columns = Columns::default();
+        for (i, cell) in header.iter().enumerate() {
+            let name = cell_text(*cell).unwrap_or_default().to_lowercase();
+            if name.contains("year") {
+                columns.year = Some(i);
+            } else if name.contains("composer") {
+                columns.composer = Some(i);
+            } else if name.contains("lyric") {
+                columns.lyricist = Some(i);
+            } else if name.contains("book") {
+                columns.book_writer = Some(i);
+            }
+        }
+        columns
+    }
+}
+
+/// The musicals of the list tables of a page: the title of the first cell of
+/// each row with the Wikipedia page it links to, and the metadata found in
+/// the other columns. Titles which don't link to any page are returned with
+/// an empty url.
+pub fn extract_rows(html: &str) -> Vec<Row> {
+    let document = Html::parse_document(html);
+    let title = selector("i");
+    let link = selector("a[title]");
+    let mut rows = vec![];
+    for table in document.select(&selector("table.wikitable")) {
+        let mut columns = Columns::default();
+        for row in child_elements(table, "tbody").flat_map(|tbody| child_elements(tbody, "tr")) {
+            let cells = cells(row);
+            if cells.iter().all(|cell| cell.value().name() == "th") {
+                columns = Columns::from_header(&cells);
+                continue;
+            }
+            let Some(title) = cells.first().and_then(|cell| cell.select(&title).next()) else {
+                continue;
+            };
+            let column = |i: Option<usize>| {
+                i.and_then(|i| cells.get(i))
+                    .and_then(|cell| cell_text(*cell))
+            };
+            let (name, url) = match title.select(&link).next() {
+                Some(link) => (
+                    link.text().collect(),
+                    link.value().attr("title").unwrap_or_default().to_string(),
+                ),
+                None => (title.text().collect(), String::new()),
+            };
+            rows.push(Row {
+                name,
+                url,
+                year: column(columns.year).as_deref().and_then(year),
+                composer: column(columns.composer),
+                lyricist: column(columns.lyricist),
+                book_writer: column(columns.book_writer),
+            });
+        }
+    }
+    rows
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn names(rows: &[Row]) -> Vec<(&str, &str)> {
+        rows.iter()
+            .map(|row| (row.name.as_str(), row.url.as_str()))
+            .collect()
+    }
+
+    #[test]
+    fn extracts_a_to_l() {
+        let rows = extract_rows(include_str!(
+            "../tests/fixtures/list_of_musicals_a_to_l.html"
+        ));
+        assert_eq!(
+            names(&rows),
+            vec![
+                ("101 Dalmatians", "101 Dalmatians (2022 musical)"),
+                (
+                    "35mm: A Musical Exhibition",
+                    "35mm: A Musical Exhibition (page does not exist)"
+                ),
+                ("42nd Street", "42nd Street (film)"),
+                ("42nd Street", "42nd Street (musical)"),
+                ("Ain't Misbehavin'", "Ain't Misbehavin' (musical)"),
+                ("Aladdin", "Aladdin (1958 film)"),
+                ("Anne & Gilbert", "Anne & Gilbert"),
+                ("Annie Get Your Gun", ""),
+            ]
+        );
+    }
+
+    #[test]
+    fn extracts_m_to_z() {
+        let rows = extract_rows(include_str!(
+            "../tests/fixtures/list_of_musicals_m_to_z.html"
+        ));
+        assert_eq!(
+            names(&rows),
+            vec![
+                ("Les Misérables", "Les Misérables (musical)"),
+                ("Les Misérables", "Les Misérables (musical)"),
+                ("Miss Saigon", "Miss Saigon"),
+                ("Ziegfeld Follies of 1909", "Ziegfeld Follies"),
+                ("Zombie Prom", "Zombie Prom"),
+            ]
+        );
+    }
+
+    #[test]
+    fn extracts_metadata() {
+        let rows = extract_rows(include_str!(
+            "../tests/fixtures/list_of_musicals_a_to_l.html"
+        ));
+        assert_eq!(
+            rows[0],
+            Row {
+                name: "101 Dalmatians".to_string(),
+                url: "101 Dalmatians (2022 musical)".to_string(),
+                year: Some(2022),
+                composer: Some("Douglas Hodge".to_string()),
+                lyricist: Some("Douglas Hodge".to_string()),
+                book_writer: Some("Johnny McKnight, based on the novel by Dodie Smith".to_string()),
+            }
+        );
+        assert_eq!(rows[1].book_writer, None);
+        assert_eq!(rows[7].year, Some(1999));
+    }
+}
@@ -1,1198 +0,0 @@
-include!("model.rs");
-pub static MUSICALS: once_cell::sync::Lazy<Vec<Musical>> = once_cell::sync::Lazy::new(|| {
-    vec![
-Musical { id: 1, name: "101 Dalmatians".to_string(), url: "101 Dalmatians (2022 musical)".to_string() },
-Musical { id: 2, name: "Dodie Smith".to_string(), url: "Dodie Smith".to_string() },
-Musical { id: 3, name: "110 in the Shade".to_string(), url: "110 in the Shade".to_string() },
-Musical { id: 4, name: "13".to_string(), url: "13 (musical)".to_string() },
-Musical { id: 5, name: "1492 Up to Date".to_string(), url: "1492 Up to Date".to_string() },
-Musical { id: 6, name: "1600 Pennsylvania Avenue".to_string(), url: "1600 Pennsylvania Avenue (musical)".to_string() },
-Musical { id: 7, name: "1776".to_string(), url: "1776 (musical)".to_string() },
-Musical { id: 8, name: "1789: Les Amants de la Bastille".to_string(), url: "1789: Les Amants de la Bastille".to_string() },
-Musical { id: 9, name: "21 Chump Street".to_string(), url: "21 Chump Street".to_string() },
-Musical { id: 10, name: "3 Musketiers".to_string(), url: "3 Musketiers".to_string() },
-Musical { id: 11, name: "35mm: A Musical Exhibition".to_string(), url: "35mm: A Musical Exhibition (page does not exist)".to_string() },
-Musical { id: 12, name: "42nd Street".to_string(), url: "42nd Street (film)".to_string() },
-Musical { id: 13, name: "42nd Street".to_string(), url: "42nd Street (musical)".to_string() },
-Musical { id: 14, name: "70, Girls, 70".to_string(), url: "70, Girls, 70".to_string() },
-Musical { id: 15, name: "8 femmes".to_string(), url: "8 Women".to_string() },
-Musical { id: 16, name: "9 to 5".to_string(), url: "9 to 5 (musical)".to_string() },
-Musical { id: 17, name: "A... My Name Is Alice".to_string(), url: "A... My Name Is Alice".to_string() },
-Musical { id: 18, name: "Aaron Slick from Punkin Crick".to_string(), url: "Aaron Slick from Punkin Crick".to_string() },
-Musical { id: 19, name: "ABBAcadabra".to_string(), url: "Abbacadabra".to_string() },
-Musical { id: 20, name: "About Face".to_string(), url: "About Face (1952 film)".to_string() },
-Musical { id: 21, name: "Absolute Beginners".to_string(), url: "Absolute Beginners (film)".to_string() },
-Musical { id: 22, name: "Abyssinia".to_string(), url: "Abyssinia (musical)".to_string() },
-Musical { id: 23, name: "Ace".to_string(), url: "Ace (musical)".to_string() },
-Musical { id: 24, name: "Ace of Clubs".to_string(), url: "Ace of Clubs (musical)".to_string() },
-Musical { id: 25, name: "Acorn Antiques: The Musical!".to_string(), url: "Acorn Antiques: The Musical!".to_string() },
-Musical { id: 26, name: "Across the Universe".to_string(), url: "Across the Universe (film)".to_string() },
-Musical { id: 27, name: "Adding Machine".to_string(), url: "Adding Machine (musical)".to_string() },
-Musical { id: 28, name: "Adèle".to_string(), url: "Adele (musical)".to_string() },
-Musical { id: 29, name: "Adonis".to_string(), url: "Adonis (musical)".to_string() },
-Musical { id: 30, name: "Affair in Trinidad".to_string(), url: "Affair in Trinidad".to_string() },
-Musical { id: 31, name: "Afgar".to_string(), url: "Afgar".to_string() },
-Musical { id: 32, name: "After the Ball".to_string(), url: "After the Ball (musical)".to_string() },
-Musical { id: 33, name: "After Midnight".to_string(), url: "After Midnight (musical)".to_string() },
-Musical { id: 34, name: "Aida".to_string(), url: "Aida (musical)".to_string() },
-Musical { id: 35, name: "Ain't Misbehavin'".to_string(), url: "Ain&#39;t Misbehavin&#39; (musical)".to_string() },
-Musical { id: 36, name: "Ain't Supposed to Die a Natural Death".to_string(), url: "Ain&#39;t Supposed to Die a Natural Death".to_string() },
-Musical { id: 37, name: "Ain't Too Proud".to_string(), url: "Ain&#39;t Too Proud (musical)".to_string() },
-Musical { id: 38, name: "Aladdin".to_string(), url: "Aladdin (1958 film)".to_string() },
-Musical { id: 39, name: "Aladdin".to_string(), url: "Aladdin (1979 musical)".to_string() },
-Musical { id: 40, name: "Aladdin".to_string(), url: "Aladdin (1992 Disney film)".to_string() },
-Musical { id: 41, name: "Alexander's Ragtime Band".to_string(), url: "Alexander&#39;s Ragtime Band (film)".to_string() },
-Musical { id: 42, name: "Ali Baba Goes to Town".to_string(), url: "Ali Baba Goes to Town".to_string() },
-Musical { id: 43, name: "Alice by Heart".to_string(), url: "Alice by Heart".to_string() },
-Musical { id: 44, name: "Alice in Wonderland".to_string(), url: "Alice in Wonderland (musical)".to_string() },
-Musical { id: 45, name: "Alice in Wonderland".to_string(), url: "Alice in Wonderland (1951 film)".to_string() },
-Musical { id: 46, name: "Alice in Wonderland".to_string(), url: "Alice in Wonderland (1976 film)".to_string() },
-Musical { id: 47, name: "Alice in Wonderland".to_string(), url: "Alice in Wonderland (1985 film)".to_string() },
-Musical { id: 48, name: "Alice in Wonderland (or What's a Nice Kid Like You Doing in a Place Like This?)".to_string(), url: "Alice in Wonderland (or What&#39;s a Nice Kid Like You Doing in a Place Like This?)".to_string() },
-Musical { id: 49, name: "Alice's Adventures in Wonderland".to_string(), url: "Alice&#39;s Adventures in Wonderland (1972 film)".to_string() },
-Musical { id: 50, name: "Alive and Kicking".to_string(), url: "Alive and Kicking (musical)".to_string() },
-Musical { id: 51, name: "All American".to_string(), url: "All American (musical)".to_string() },
-Musical { id: 52, name: "All Dogs Go to Heaven".to_string(), url: "All Dogs Go to Heaven".to_string() },
-Musical { id: 53, name: "All Dogs Go to Heaven 2".to_string(), url: "All Dogs Go to Heaven 2".to_string() },
-Musical { id: 54, name: "All the Fun of the Fair".to_string(), url: "All the Fun of the Fair".to_string() },
-Musical { id: 55, name: "All Hands on Deck".to_string(), url: "All Hands on Deck (1961 film)".to_string() },
-Musical { id: 56, name: "All the King's Horses".to_string(), url: "All the King&#39;s Horses (1935 film)".to_string() },
-Musical { id: 57, name: "All Out of Love".to_string(), url: "All Out of Love (musical)".to_string() },
-Musical { id: 58, name: "All Shook Up".to_string(), url: "All Shook Up (musical)".to_string() },
-Musical { id: 59, name: "All That Jazz".to_string(), url: "All That Jazz (film)".to_string() },
-Musical { id: 60, name: "Allegiance".to_string(), url: "Allegiance (musical)".to_string() },
-Musical { id: 61, name: "Allegro".to_string(), url: "Allegro (musical)".to_string() },
-Musical { id: 62, name: "Almost Famous".to_string(), url: "Almost Famous (musical)".to_string() },
-Musical { id: 63, name: "Altar Boyz".to_string(), url: "Altar Boyz".to_string() },
-Musical { id: 64, name: "Amazing Grace".to_string(), url: "Amazing Grace (musical)".to_string() },
-Musical { id: 65, name: "Ambassador".to_string(), url: "Ambassador (musical)".to_string() },
-Musical { id: 66, name: "Amélie".to_string(), url: "Amélie (musical)".to_string() },
-Musical { id: 67, name: "Amen Corner".to_string(), url: "Amen Corner (musical)".to_string() },
-Musical { id: 68, name: "American Idiot".to_string(), url: "American Idiot (musical)".to_string() },
-Musical { id: 69, name: "America Is Hard to See".to_string(), url: "America Is Hard to See".to_string() },
-Musical { id: 70, name: "American Pop".to_string(), url: "American Pop".to_string() },
-Musical { id: 71, name: "American Psycho".to_string(), url: "American Psycho (musical)".to_string() },
-Musical { id: 72, name: "Americana".to_string(), url: "Americana (revue)".to_string() },
-Musical { id: 73, name: "America's Sweetheart".to_string(), url: "America&#39;s Sweetheart (musical)".to_string() },
-Musical { id: 74, name: "Amour".to_string(), url: "Amour (musical)".to_string() },
-Musical { id: 75, name: "Amphitryon".to_string(), url: "Amphitryon (film)".to_string() },
-Musical { id: 76, name: "Anastasia".to_string(), url: "Anastasia (1997 film)".to_string() },
-Musical { id: 77, name: "Anastasia".to_string(), url: "Anastasia (musical)".to_string() },
-Musical { id: 78, name: "Anchors Aweigh".to_string(), url: "Anchors Aweigh (film)".to_string() },
-Musical { id: 79, name: "&amp; Juliet".to_string(), url: "&amp; Juliet".to_string() },
-Musical { id: 80, name: "And the Angels Sing".to_string(), url: "And the Angels Sing".to_string() },
-Musical { id: 81, name: "And the World Goes 'Round".to_string(), url: "And the World Goes &#39;Round".to_string() },
-Musical { id: 82, name: "Andy Hardy's Private Secretary".to_string(), url: "Andy Hardy&#39;s Private Secretary".to_string() },
-Musical { id: 83, name: "Angel".to_string(), url: "Angel (musical)".to_string() },
-Musical { id: 84, name: "Angel in the Wings".to_string(), url: "Angel in the Wings".to_string() },
-Musical { id: 85, name: "Angélique, Marquise des Anges".to_string(), url: "Angelique (novel series)".to_string() },
-Musical { id: 86, name: "Ani: A Parody".to_string(), url: "Ani (musical)".to_string() },
-Musical { id: 87, name: "Animal Crackers".to_string(), url: "Animal Crackers (musical)".to_string() },
-Musical { id: 88, name: "Ankles Aweigh".to_string(), url: "Ankles Aweigh".to_string() },
-Musical { id: 89, name: "Anna and the Apocalypse".to_string(), url: "Anna and the Apocalypse".to_string() },
-Musical { id: 90, name: "Anna Karenina".to_string(), url: "Anna Karenina (musical)".to_string() },
-Musical { id: 91, name: "Anne &amp; Gilbert".to_string(), url: "Anne &amp; Gilbert".to_string() },
-Musical { id: 92, name: "Anne of Green Gables".to_string(), url: "Anne of Green Gables: The Musical".to_string() },
-Musical { id: 93, name: "Annie".to_string(), url: "Annie (musical)".to_string() },
-Musical { id: 94, name: "Annie Get Your Gun".to_string(), url: "Annie Get Your Gun (musical)".to_string() },
-Musical { id: 95, name: "Annie Warbucks".to_string(), url: "Annie Warbucks".to_string() },
-Musical { id: 96, name: "Another Cinderella Story".to_string(), url: "Another Cinderella Story".to_string() },
-Musical { id: 97, name: "Anya".to_string(), url: "Anya (musical)".to_string() },
-Musical { id: 98, name: "Anyone Can Whistle".to_string(), url: "Anyone Can Whistle".to_string() },
-Musical { id: 99, name: "Anything Goes".to_string(), url: "Anything Goes".to_string() },
-Musical { id: 100, name: "Applause".to_string(), url: "Applause (musical)".to_string() },
-Musical { id: 101, name: "April in Paris".to_string(), url: "April in Paris (film)".to_string() },
-Musical { id: 102, name: "April Love".to_string(), url: "April Love (film)".to_string() },
-Musical { id: 103, name: "Are You With It?".to_string(), url: "Are You with It? (musical)".to_string() },
-Musical { id: 104, name: "Are You with It?".to_string(), url: "Are You with It?".to_string() },
-Musical { id: 105, name: "Around the World".to_string(), url: "Around the World (musical)".to_string() },
-Musical { id: 106, name: "Arthur! Arthur!".to_string(), url: "Arthur! Arthur!".to_string() },
-Musical { id: 107, name: "Artists and Models".to_string(), url: "Artists and Models (1937 film)".to_string() },
-Musical { id: 108, name: "Artists and Models".to_string(), url: "Artists and Models".to_string() },
-Musical { id: 109, name: "As the Girls Go".to_string(), url: "As the Girls Go".to_string() },
-Musical { id: 110, name: "As Long as They're Happy".to_string(), url: "As Long as They&#39;re Happy".to_string() },
-Musical { id: 111, name: "As Thousands Cheer".to_string(), url: "As Thousands Cheer".to_string() },
-Musical { id: 112, name: "Aspects of Love".to_string(), url: "Aspects of Love".to_string() },
-Musical { id: 113, name: "Assassins".to_string(), url: "Assassins (musical)".to_string() },
-Musical { id: 114, name: "At the Circus".to_string(), url: "At the Circus".to_string() },
-Musical { id: 115, name: "At the Drop of Another Hat".to_string(), url: "At the Drop of Another Hat".to_string() },
-Musical { id: 116, name: "At the Drop of a Hat".to_string(), url: "At the Drop of a Hat".to_string() },
-Musical { id: 117, name: "At Home Abroad".to_string(), url: "At Home Abroad".to_string() },
-Musical { id: 118, name: "At Long Last Love".to_string(), url: "At Long Last Love".to_string() },
-Musical { id: 119, name: "At War with the Army".to_string(), url: "At War with the Army".to_string() },
-Musical { id: 120, name: "Athena".to_string(), url: "Athena (1954 film)".to_string() },
-Musical { id: 121, name: "Atlantic City".to_string(), url: "Atlantic City (1944 film)".to_string() },
-Musical { id: 122, name: "Autant en emporte le vent".to_string(), url: "Autant en emporte le vent".to_string() },
-Musical { id: 123, name: "Avenue Q".to_string(), url: "Avenue Q".to_string() },
-Musical { id: 124, name: "Babes in Arms".to_string(), url: "Babes in Arms".to_string() },
-Musical { id: 125, name: "Babes in Toyland".to_string(), url: "Babes in Toyland (operetta)".to_string() },
-Musical { id: 126, name: "Babes on Broadway".to_string(), url: "Babes on Broadway".to_string() },
-Musical { id: 127, name: "Baby".to_string(), url: "Baby (musical)".to_string() },
-Musical { id: 128, name: "Baby It's You!".to_string(), url: "Baby It&#39;s You!".to_string() },
-Musical { id: 129, name: "Baby Talk".to_string(), url: "de:Baby Talk (Musical)".to_string() },
-Musical { id: 130, name: "Back to the 80's".to_string(), url: "Back to the 80s (musical)".to_string() },
-Musical { id: 131, name: "Back to the Future: The Musical".to_string(), url: "Back to the Future: The Musical".to_string() },
-Musical { id: 132, name: "Back to the Future".to_string(), url: "Back to the Future".to_string() },
-Musical { id: 133, name: " Bad Cinderella".to_string(), url: "Cinderella (Lloyd Webber musical)".to_string() },
-Musical { id: 134, name: "Bad Girls".to_string(), url: "Bad Girls: The Musical".to_string() },
-Musical { id: 135, name: "Bajour".to_string(), url: "Bajour (musical)".to_string() },
-Musical { id: 136, name: "Baker Street".to_string(), url: "Baker Street (musical)".to_string() },
-Musical { id: 137, name: "Ball im Savoy".to_string(), url: "Ball im Savoy".to_string() },
-Musical { id: 138, name: "Ballroom".to_string(), url: "Ballroom (musical)".to_string() },
-Musical { id: 139, name: "Bambi".to_string(), url: "Bambi".to_string() },
-Musical { id: 140, name: "The Band".to_string(), url: "The Band (musical)".to_string() },
-Musical { id: 141, name: "Band Waggon".to_string(), url: "Band Waggon (film)".to_string() },
-Musical { id: 142, name: "Bandstand".to_string(), url: "Bandstand (musical)".to_string() },
-Musical { id: 143, name: "Banjo Eyes".to_string(), url: "Banjo Eyes".to_string() },
-Musical { id: 144, name: "Bar Mitzvah Boy".to_string(), url: "Bar Mitzvah Boy (musical)".to_string() },
-Musical { id: 145, name: "Barbarella".to_string(), url: "Barbarella (musical)".to_string() },
-Musical { id: 146, name: "The Bardy Bunch".to_string(), url: "The Bardy Bunch".to_string() },
-Musical { id: 147, name: "Bare: The Musical".to_string(), url: "Bare: The Musical".to_string() },
-Musical { id: 148, name: "Bare: A Pop Opera".to_string(), url: "Bare: A Pop Opera".to_string() },
-Musical { id: 149, name: "Barnum".to_string(), url: "Barnum (musical)".to_string() },
-Musical { id: 150, name: "Baron Trenck".to_string(), url: "Baron Trenck".to_string() },
-Musical { id: 151, name: "Bat Boy".to_string(), url: "Bat Boy: The Musical".to_string() },
-Musical { id: 152, name: "Bathhouse: The Musical!".to_string(), url: "Bathhouse: The Musical!".to_string() },
-Musical { id: 153, name: "Bat Out of Hell".to_string(), url: "Bat Out of Hell The Musical".to_string() },
-Musical { id: 154, name: "Bats".to_string(), url: "Bats (musical)".to_string() },
-Musical { id: 155, name: "Bayside! The Musical!".to_string(), url: "Saved by the Bell".to_string() },
-Musical { id: 156, name: "Be More Chill".to_string(), url: "Be More Chill (musical)".to_string() },
-Musical { id: 157, name: "Be Yourself".to_string(), url: "Be Yourself (film)".to_string() },
-Musical { id: 158, name: "Beat the Band".to_string(), url: "Beat the Band".to_string() },
-Musical { id: 159, name: "Beatlemania".to_string(), url: "Beatlemania (musical)".to_string() },
-Musical { id: 160, name: "Beautiful: The Carole King Musical".to_string(), url: "Beautiful: The Carole King Musical".to_string() },
-Musical { id: 161, name: "Beauty and the Beast".to_string(), url: "Beauty and the Beast (musical)".to_string() },
-Musical { id: 162, name: "Beauty World".to_string(), url: "Beauty World (musical)".to_string() },
-Musical { id: 163, name: "Because You're Mine".to_string(), url: "Because You&#39;re Mine".to_string() },
-Musical { id: 164, name: "Becoming Nancy".to_string(), url: "Becoming Nancy (musical)".to_string() },
-Musical { id: 165, name: "Bedknobs and Broomsticks".to_string(), url: "Bedknobs and Broomsticks (musical)".to_string() },
-Musical { id: 166, name: "Beetlejuice".to_string(), url: "Beetlejuice (musical)".to_string() },
-Musical { id: 167, name: "The Beggar Student".to_string(), url: "Der Bettelstudent".to_string() },
-Musical { id: 168, name: "Beggar's Holiday".to_string(), url: "Beggar&#39;s Holiday".to_string() },
-Musical { id: 169, name: "Beguiled Again".to_string(), url: "Beguiled Again".to_string() },
-Musical { id: 170, name: "Behind the Iron Mask".to_string(), url: "Behind the Iron Mask".to_string() },
-Musical { id: 171, name: "Bel-Ami".to_string(), url: "Bel Ami".to_string() },
-Musical { id: 172, name: "Bella: An American Tall Tale".to_string(), url: "Bella: An American Tall Tale".to_string() },
-Musical { id: 173, name: "Belle of the Nineties".to_string(), url: "Belle of the Nineties".to_string() },
-Musical { id: 174, name: "Belle of the Yukon".to_string(), url: "Belle of the Yukon".to_string() },
-Musical { id: 175, name: "Belles belles belles".to_string(), url: "Belles belles belles".to_string() },
-Musical { id: 176, name: "Bells Are Ringing".to_string(), url: "Bells Are Ringing (musical)".to_string() },
-Musical { id: 177, name: "Ben Franklin in Paris".to_string(), url: "Ben Franklin in Paris".to_string() },
-Musical { id: 178, name: "Bend It Like Beckham".to_string(), url: "Bend It Like Beckham the Musical".to_string() },
-Musical { id: 179, name: "Bernarda Alba".to_string(), url: "Bernarda Alba (musical)".to_string() },
-Musical { id: 180, name: "Bernardine".to_string(), url: "Bernardine (film)".to_string() },
-Musical { id: 181, name: "Besame Mucho".to_string(), url: "Besame Mucho El Musical".to_string() },
-Musical { id: 182, name: "Best Foot Forward".to_string(), url: "Best Foot Forward (musical)".to_string() },
-Musical { id: 183, name: "Bette! Divine Madness".to_string(), url: "Divine Madness (film)".to_string() },
-Musical { id: 184, name: "Betty".to_string(), url: "Betty (musical)".to_string() },
-Musical { id: 185, name: "Betty Blue Eyes".to_string(), url: "Betty Blue Eyes".to_string() },
-Musical { id: 186, name: "Between the Devil".to_string(), url: "Between the Devil".to_string() },
-Musical { id: 187, name: "Between the Lines".to_string(), url: "Between the Lines (musical)".to_string() },
-Musical { id: 188, name: "Betwixt!".to_string(), url: "Betwixt!".to_string() },
-Musical { id: 189, name: "Beyond the Fringe".to_string(), url: "Beyond the Fringe".to_string() },
-Musical { id: 190, name: "Big Fella".to_string(), url: "Big Fella".to_string() },
-Musical { id: 191, name: "Big Fish".to_string(), url: "Big Fish (musical)".to_string() },
-Musical { id: 192, name: "Big River".to_string(), url: "Big River (musical)".to_string() },
-Musical { id: 193, name: "Billie".to_string(), url: "Billie (1965 film)".to_string() },
-Musical { id: 194, name: "Billion Dollar Baby".to_string(), url: "Billion Dollar Baby".to_string() },
-Musical { id: 195, name: "Billy".to_string(), url: "Billy (musical)".to_string() },
-Musical { id: 196, name: "Billy Elliot".to_string(), url: "Billy Elliot the Musical".to_string() },
-Musical { id: 197, name: "Billy Noname".to_string(), url: "Billy Noname".to_string() },
-Musical { id: 198, name: "Birds of Paradise".to_string(), url: "Birds of Paradise (musical)".to_string() },
-Musical { id: 199, name: "Bitter Sweet".to_string(), url: "Bitter Sweet (operetta)".to_string() },
-Musical { id: 200, name: "Black and Blue".to_string(), url: "Black and Blue (musical)".to_string() },
-Musical { id: 201, name: "Black Friday".to_string(), url: "Black Friday (musical)".to_string() },
-Musical { id: 202, name: "Blackbirds of 1928".to_string(), url: "Blackbirds of 1928".to_string() },
-Musical { id: 203, name: "Bless the Bride".to_string(), url: "Bless the Bride".to_string() },
-Musical { id: 204, name: "Blitz!".to_string(), url: "Blitz!".to_string() },
-Musical { id: 205, name: "Blondel".to_string(), url: "Blondel (musical)".to_string() },
-Musical { id: 206, name: "Blood Brothers".to_string(), url: "Blood Brothers (musical)".to_string() },
-Musical { id: 207, name: "Bloody Bloody Andrew Jackson".to_string(), url: "Bloody Bloody Andrew Jackson".to_string() },
-Musical { id: 208, name: "Bloomer Girl".to_string(), url: "Bloomer Girl".to_string() },
-Musical { id: 209, name: "Blossom Time".to_string(), url: "Das Dreimäderlhaus".to_string() },
-Musical { id: 210, name: "Blue Hawaii".to_string(), url: "Blue Hawaii".to_string() },
-Musical { id: 211, name: "Blue Rhythm".to_string(), url: "Blue Rhythm".to_string() },
-Musical { id: 212, name: "Blue Skies".to_string(), url: "Blue Skies (1946 film)".to_string() },
-Musical { id: 213, name: "Bluebell in Fairyland".to_string(), url: "Bluebell in Fairyland".to_string() },
-Musical { id: 214, name: "Blues in the Night".to_string(), url: "Blues in the Night (film)".to_string() },
-Musical { id: 215, name: "Blues in the Night".to_string(), url: "Blues in the Night (musical)".to_string() },
-Musical { id: 216, name: "Boccaccio".to_string(), url: "Boccaccio (1936 film)".to_string() },
-Musical { id: 217, name: "Bombay Dreams".to_string(), url: "Bombay Dreams".to_string() },
-Musical { id: 218, name: "Bombo".to_string(), url: "Bombo (musical)".to_string() },
-Musical { id: 219, name: "Bonnie &amp; Clyde".to_string(), url: "Bonnie &amp; Clyde (musical)".to_string() },
-Musical { id: 220, name: "Boobs!".to_string(), url: "Boobs! The Musical".to_string() },
-Musical { id: 221, name: "Boogie Nights".to_string(), url: "Boogie Nights (musical)".to_string() },
-Musical { id: 222, name: "Boots and Saddles".to_string(), url: "Boots and Saddles (1937 film)".to_string() },
-Musical { id: 223, name: "Boots! Boots!".to_string(), url: "Boots! Boots!".to_string() },
-Musical { id: 224, name: "Born to Dance".to_string(), url: "Born to Dance".to_string() },
-Musical { id: 225, name: "Bottoms Up".to_string(), url: "Bottoms Up (1934 film)".to_string() },
-Musical { id: 226, name: "Boy Meets Boy".to_string(), url: "Boy Meets Boy (musical)".to_string() },
-Musical { id: 227, name: "Bran Nue Dae".to_string(), url: "Bran Nue Dae".to_string() },
-Musical { id: 228, name: "Bravo Giovanni".to_string(), url: "Bravo Giovanni".to_string() },
-Musical { id: 229, name: "Breakfast at Tiffany's".to_string(), url: "Breakfast at Tiffany&#39;s (musical)".to_string() },
-Musical { id: 230, name: "Brewster's Millions".to_string(), url: "Brewster&#39;s Millions (1935 film)".to_string() },
-Musical { id: 231, name: "Brigadoon".to_string(), url: "Brigadoon".to_string() },
-Musical { id: 232, name: "Bright Eyes".to_string(), url: "Bright Eyes (1934 film)".to_string() },
-Musical { id: 233, name: "Bright Lights, Big City".to_string(), url: "Bright Lights, Big City (musical)".to_string() },
-Musical { id: 234, name: "Bright Lights of 1944".to_string(), url: "Bright Lights of 1944".to_string() },
-Musical { id: 235, name: "Bright Star".to_string(), url: "Bright Star (musical)".to_string() },
-Musical { id: 236, name: "Bring Back Birdie".to_string(), url: "Bring Back Birdie".to_string() },
-Musical { id: 237, name: "Bring in 'da Noise, Bring in 'da Funk".to_string(), url: "Bring in &#39;da Noise, Bring in &#39;da Funk".to_string() },
-Musical { id: 238, name: "Bring It On".to_string(), url: "Bring It On: The Musical".to_string() },
-Musical { id: 239, name: "Bring Your Smile Along".to_string(), url: "Bring Your Smile Along".to_string() },
-Musical { id: 240, name: "Britannia of Billingsgate".to_string(), url: "Britannia of Billingsgate".to_string() },
-Musical { id: 241, name: "Broadway Gondolier".to_string(), url: "Broadway Gondolier".to_string() },
-Musical { id: 242, name: "Broadway Melody of 1936".to_string(), url: "Broadway Melody of 1936".to_string() },
-Musical { id: 243, name: "Broadway Melody of 1938".to_string(), url: "Broadway Melody of 1938".to_string() },
-Musical { id: 244, name: "Broadway Melody of 1940".to_string(), url: "Broadway Melody of 1940".to_string() },
-Musical { id: 245, name: "Broadway Through a Keyhole".to_string(), url: "Broadway Through a Keyhole".to_string() },
-Musical { id: 246, name: "Brooklyn".to_string(), url: "Brooklyn (musical)".to_string() },
-Musical { id: 247, name: "Bubble Boy".to_string(), url: "Bubble Boy (musical)".to_string() },
-Musical { id: 248, name: "Bubbling Brown Sugar".to_string(), url: "Bubbling Brown Sugar".to_string() },
-Musical { id: 249, name: "Buck Benny Rides Again".to_string(), url: "Buck Benny Rides Again".to_string() },
-Musical { id: 250, name: "Buck Privates".to_string(), url: "Buck Privates".to_string() },
-Musical { id: 251, name: "Buddies".to_string(), url: "Buddies (play)".to_string() },
-Musical { id: 252, name: "Buddy – The Buddy Holly Story".to_string(), url: "Buddy – The Buddy Holly Story".to_string() },
-Musical { id: 253, name: "Bugs Bunny on Broadway".to_string(), url: "Bugs Bunny on Broadway".to_string() },
-Musical { id: 254, name: "Bugsy Malone".to_string(), url: "Bugsy Malone".to_string() },
-Musical { id: 255, name: "Bullets over Broadway".to_string(), url: "Bullets over Broadway".to_string() },
-Musical { id: 256, name: "Bumblescratch".to_string(), url: "Bumblescratch".to_string() },
-Musical { id: 257, name: "Bundle of Joy".to_string(), url: "Bundle of Joy".to_string() },
-Musical { id: 258, name: "The Bunyip".to_string(), url: "The Bunyip (musical)".to_string() },
-Musical { id: 259, name: "Burlesque".to_string(), url: "Burlesque (2010 American film)".to_string() },
-Musical { id: 260, name: "Bus Stop".to_string(), url: "Bus Stop (1956 film)".to_string() },
-Musical { id: 261, name: "By the Beautiful Sea".to_string(), url: "By the Beautiful Sea (musical)".to_string() },
-Musical { id: 262, name: "By Jeeves".to_string(), url: "By Jeeves".to_string() },
-Musical { id: 263, name: "By Jupiter".to_string(), url: "By Jupiter".to_string() },
-Musical { id: 264, name: "By the Light of the Silvery Moon".to_string(), url: "By the Light of the Silvery Moon (film)".to_string() },
-Musical { id: 265, name: "Bye Bye Birdie".to_string(), url: "Bye Bye Birdie".to_string() },
-Musical { id: 266, name: "Cab Calloway's Hi-De-Ho".to_string(), url: "Cab Calloway&#39;s Hi-De-Ho".to_string() },
-Musical { id: 267, name: "Cabaret".to_string(), url: "Cabaret (musical)".to_string() },
-Musical { id: 268, name: "Cabin in the Sky".to_string(), url: "Cabin in the Sky (musical)".to_string() },
-Musical { id: 269, name: "Calamity Jane".to_string(), url: "Calamity Jane (film)".to_string() },
-Musical { id: 270, name: "Call Me Madam".to_string(), url: "Call Me Madam".to_string() },
-Musical { id: 271, name: "Call Me Mister".to_string(), url: "Call Me Mister".to_string() },
-Musical { id: 272, name: "Call of the Flesh".to_string(), url: "Call of the Flesh".to_string() },
-Musical { id: 273, name: "Camelot".to_string(), url: "Camelot (musical)".to_string() },
-Musical { id: 274, name: "Camp".to_string(), url: "Camp (2003 film)".to_string() },
-Musical { id: 275, name: "Camp Rock".to_string(), url: "Camp Rock".to_string() },
-Musical { id: 276, name: "Camp Rock 2: The Final Jam".to_string(), url: "Camp Rock 2: The Final Jam".to_string() },
-Musical { id: 277, name: "Can-Can".to_string(), url: "Can-Can (musical)".to_string() },
-Musical { id: 278, name: "Candide".to_string(), url: "Candide (operetta)".to_string() },
-Musical { id: 279, name: "Cannibal".to_string(), url: "Cannibal! The Musical".to_string() },
-Musical { id: 280, name: "Can't Help Singing".to_string(), url: "Can&#39;t Help Singing".to_string() },
-Musical { id: 281, name: "Can't Stop the Music".to_string(), url: "Can&#39;t Stop the Music".to_string() },
-Musical { id: 282, name: "Canterbury Tales".to_string(), url: "Canterbury Tales (musical)".to_string() },
-Musical { id: 283, name: "Captain January".to_string(), url: "Captain January (1936 film)".to_string() },
-Musical { id: 284, name: "Captain Louie".to_string(), url: "Captain Louie".to_string() },
-Musical { id: 285, name: "Carefree".to_string(), url: "Carefree (film)".to_string() },
-Musical { id: 286, name: "Carmelina".to_string(), url: "Carmelina".to_string() },
-Musical { id: 287, name: "Carmen Jones".to_string(), url: "Carmen Jones".to_string() },
-Musical { id: 288, name: "Carnival in Flanders".to_string(), url: "Carnival in Flanders (musical)".to_string() },
-Musical { id: 289, name: "Carnival!".to_string(), url: "Carnival!".to_string() },
-Musical { id: 290, name: "Caroline, or Change".to_string(), url: "Caroline, or Change".to_string() },
-Musical { id: 291, name: "Carousel".to_string(), url: "Carousel (musical)".to_string() },
-Musical { id: 292, name: "Carrie".to_string(), url: "Carrie (musical)".to_string() },
-Musical { id: 293, name: "Casbah".to_string(), url: "Casbah (film)".to_string() },
-Musical { id: 294, name: "Castles in the Air".to_string(), url: "Castles in the Air (musical)".to_string() },
-Musical { id: 295, name: "Catch Me If You Can".to_string(), url: "Catch Me If You Can (musical)".to_string() },
-Musical { id: 296, name: "Cats".to_string(), url: "Cats (musical)".to_string() },
-Musical { id: 297, name: "Cats Don't Dance".to_string(), url: "Cats Don&#39;t Dance".to_string() },
-Musical { id: 298, name: "Celebration".to_string(), url: "Celebration (musical)".to_string() },
-Musical { id: 299, name: "Centennial Summer".to_string(), url: "Centennial Summer".to_string() },
-Musical { id: 300, name: "Champagne Charlie".to_string(), url: "Champagne Charlie (musical)".to_string() },
-Musical { id: 301, name: "Chaplin".to_string(), url: "Chaplin (2006 musical)".to_string() },
-Musical { id: 302, name: "Charlie and the Chocolate Factory".to_string(), url: "Charlie and the Chocolate Factory (musical)".to_string() },
-Musical { id: 303, name: "Charlie Girl".to_string(), url: "Charlie Girl".to_string() },
-Musical { id: 304, name: "Charlotte Sweet".to_string(), url: "Charlotte Sweet".to_string() },
-Musical { id: 305, name: "Charlotte's Web".to_string(), url: "Charlotte&#39;s Web (1973 film)".to_string() },
-Musical { id: 306, name: "Chasing Rainbows".to_string(), url: "Chasing Rainbows (1930 film)".to_string() },
-Musical { id: 307, name: "Chess".to_string(), url: "Chess (musical)".to_string() },
-Musical { id: 308, name: "Chicago".to_string(), url: "Chicago (musical)".to_string() },
-Musical { id: 309, name: "Children of Eden".to_string(), url: "Children of Eden".to_string() },
-Musical { id: 310, name: "Children's Letters to God".to_string(), url: "Children&#39;s Letters to God".to_string() },
-Musical { id: 311, name: "Chitty Chitty Bang Bang".to_string(), url: "Chitty Chitty Bang Bang (musical)".to_string() },
-Musical { id: 312, name: "Chris and the Wonderful Lamp".to_string(), url: "Chris and the Wonderful Lamp".to_string() },
-Musical { id: 313, name: "Chronicle of a Death Foretold".to_string(), url: "Chronicle of a Death Foretold (musical)".to_string() },
-Musical { id: 314, name: "Chu Chin Chow".to_string(), url: "Chu Chin Chow".to_string() },
-Musical { id: 315, name: "Church Basement Ladies".to_string(), url: "Church Basement Ladies".to_string() },
-Musical { id: 316, name: "Cinderella".to_string(), url: "Cinderella (1950 film)".to_string() },
-Musical { id: 317, name: "Cinderella".to_string(), url: "Cinderella (2013 Broadway production)".to_string() },
-Musical { id: 318, name: "Cindy".to_string(), url: "Cindy (musical)".to_string() },
-Musical { id: 319, name: "Cirque Dreams Holidaze".to_string(), url: "Cirque Productions".to_string() },
-Musical { id: 320, name: "City of Angels".to_string(), url: "City of Angels (musical)".to_string() },
-Musical { id: 321, name: "Clambake".to_string(), url: "Clambake (film)".to_string() },
-Musical { id: 322, name: "Clinton: The Musical".to_string(), url: "Clinton: The Musical".to_string() },
-Musical { id: 323, name: "Close Harmony".to_string(), url: "Close Harmony (1929 film)".to_string() },
-Musical { id: 324, name: "Closer Than Ever".to_string(), url: "Closer Than Ever".to_string() },
-Musical { id: 325, name: "Closer to Heaven".to_string(), url: "Closer to Heaven (musical)".to_string() },
-Musical { id: 326, name: "Clue".to_string(), url: "Clue (musical)".to_string() },
-Musical { id: 327, name: "Clueless, The Musical".to_string(), url: "Clueless (musical)".to_string() },
-Musical { id: 328, name: "Coco".to_string(), url: "Coco (musical)".to_string() },
-Musical { id: 329, name: "Colleen".to_string(), url: "Colleen (1936 film)".to_string() },
-Musical { id: 330, name: "College Humor".to_string(), url: "College Humor (film)".to_string() },
-Musical { id: 331, name: "Colma".to_string(), url: "Colma: The Musical".to_string() },
-Musical { id: 332, name: "Come from Away".to_string(), url: "Come from Away".to_string() },
-Musical { id: 333, name: "Come Summer".to_string(), url: "Come Summer".to_string() },
-Musical { id: 334, name: "Comin' Round the Mountain".to_string(), url: "Comin&#39; Round the Mountain".to_string() },
-Musical { id: 335, name: "Company".to_string(), url: "Company (musical)".to_string() },
-Musical { id: 336, name: "Coney Island".to_string(), url: "Coney Island (1943 film)".to_string() },
-Musical { id: 337, name: "Contact".to_string(), url: "Contact (musical)".to_string() },
-Musical { id: 338, name: "Conversation Piece".to_string(), url: "Conversation Piece (musical)".to_string() },
-Musical { id: 339, name: "Copacabana".to_string(), url: "Copacabana (1947 film)".to_string() },
-Musical { id: 340, name: "Copacabana".to_string(), url: "Copacabana (musical)".to_string() },
-Musical { id: 341, name: "Coraline".to_string(), url: "Coraline (musical)".to_string() },
-Musical { id: 342, name: "Corpse Bride".to_string(), url: "Corpse Bride".to_string() },
-Musical { id: 343, name: "Cotton Patch Gospel".to_string(), url: "Cotton Patch Gospel".to_string() },
-Musical { id: 344, name: "Countess Maritza".to_string(), url: "Countess Maritza".to_string() },
-Musical { id: 345, name: "Cover Girl".to_string(), url: "Cover Girl (film)".to_string() },
-Musical { id: 346, name: "Cowardy Custard".to_string(), url: "Cowardy Custard".to_string() },
-Musical { id: 347, name: "Cowboy from Brooklyn".to_string(), url: "Cowboy from Brooklyn".to_string() },
-Musical { id: 348, name: "Crazy for You".to_string(), url: "Crazy for You (musical)".to_string() },
-Musical { id: 349, name: "Crossroads".to_string(), url: "Crossroads (1986 film)".to_string() },
-Musical { id: 350, name: "Cruel Intentions: The Musical".to_string(), url: "Cruel Intentions: The Musical".to_string() },
-Musical { id: 351, name: "Cry for Us All".to_string(), url: "Cry for Us All".to_string() },
-Musical { id: 352, name: "Cry-Baby".to_string(), url: "Cry-Baby (musical)".to_string() },
-Musical { id: 353, name: "Curley McDimple".to_string(), url: "Curley McDimple".to_string() },
-Musical { id: 354, name: "Curtains".to_string(), url: "Curtains (musical)".to_string() },
-Musical { id: 355, name: "Cyrano".to_string(), url: "Cyrano (musical)".to_string() },
-Musical { id: 356, name: "Cyrano".to_string(), url: "Cyrano: The Musical".to_string() },
-Musical { id: 357, name: "Daddy Cool".to_string(), url: "Daddy Cool (musical)".to_string() },
-Musical { id: 358, name: "Daddy Long Legs".to_string(), url: "Daddy Long Legs (1955 film)".to_string() },
-Musical { id: 359, name: "".to_string(), url: "Daddy Long Legs (musical)".to_string() },
-Musical { id: 360, name: "Dames".to_string(), url: "Dames (film)".to_string() },
-Musical { id: 361, name: "Dames at Sea".to_string(), url: "Dames at Sea".to_string() },
-Musical { id: 362, name: "Damn Yankees".to_string(), url: "Damn Yankees".to_string() },
-Musical { id: 363, name: "Dance a Little Closer".to_string(), url: "Dance a Little Closer".to_string() },
-Musical { id: 364, name: "Dance of the Vampires".to_string(), url: "Dance of the Vampires (musical)".to_string() },
-Musical { id: 365, name: "Dance with Me".to_string(), url: "Dance with Me (musical)".to_string() },
-Musical { id: 366, name: "Dancer in the Dark".to_string(), url: "Dancer in the Dark".to_string() },
-Musical { id: 367, name: "Dancin'".to_string(), url: "Dancin&#39;".to_string() },
-Musical { id: 368, name: "Dancing Lady".to_string(), url: "Dancing Lady".to_string() },
-Musical { id: 369, name: "Dangerous When Wet".to_string(), url: "Dangerous When Wet".to_string() },
-Musical { id: 370, name: "Darby O'Gill and the Little People".to_string(), url: "Darby O&#39;Gill and the Little People".to_string() },
-Musical { id: 371, name: "Darling Lili".to_string(), url: "Darling Lili".to_string() },
-Musical { id: 372, name: "Darling of the Day".to_string(), url: "Darling of the Day (musical)".to_string() },
-Musical { id: 373, name: "Dear Edwina".to_string(), url: "Dear Edwina".to_string() },
-Musical { id: 374, name: "Dear Evan Hansen".to_string(), url: "Dear Evan Hansen".to_string() },
-Musical { id: 375, name: "Dear World".to_string(), url: "Dear World".to_string() },
-Musical { id: 376, name: "Dearest Enemy".to_string(), url: "Dearest Enemy".to_string() },
-Musical { id: 377, name: "Death of a Salesman".to_string(), url: "Death of a Salesman".to_string() },
-Musical { id: 378, name: "Death Note".to_string(), url: "Death Note: The Musical".to_string() },
-Musical { id: 379, name: "Debbie Does Dallas".to_string(), url: "Debbie Does Dallas: The Musical".to_string() },
-Musical { id: 380, name: "Deep in My Heart".to_string(), url: "Deep in My Heart (1954 film)".to_string() },
-Musical { id: 381, name: "Delicious".to_string(), url: "Delicious (film)".to_string() },
-Musical { id: 382, name: "Delightfully Dangerous".to_string(), url: "Delightfully Dangerous".to_string() },
-Musical { id: 383, name: "De-Lovely".to_string(), url: "De-Lovely".to_string() },
-Musical { id: 384, name: "Descendants".to_string(), url: "Descendants (2015 film)".to_string() },
-Musical { id: 385, name: "Descendants 2".to_string(), url: "Descendants 2".to_string() },
-Musical { id: 386, name: "Descendants 3".to_string(), url: "Descendants 3".to_string() },
-Musical { id: 387, name: "Dessa Rose".to_string(), url: "Dessa Rose (musical)".to_string() },
-Musical { id: 388, name: "Destry Rides Again".to_string(), url: "Destry Rides Again".to_string() },
-Musical { id: 389, name: "Destry Rides Again".to_string(), url: "Destry Rides Again (musical)".to_string() },
-Musical { id: 390, name: "Devil-May-Care".to_string(), url: "Devil-May-Care".to_string() },
-Musical { id: 391, name: "The Devil Wears Prada".to_string(), url: "The Devil Wears Prada (musical)".to_string() },
-Musical { id: 392, name: "Diamonds".to_string(), url: "Diamonds (musical)".to_string() },
-Musical { id: 393, name: "Diana".to_string(), url: "Diana (musical)".to_string() },
-Musical { id: 394, name: "Dick Tracy".to_string(), url: "Dick Tracy (1990 film)".to_string() },
-Musical { id: 395, name: "Different Times".to_string(), url: "Different Times (musical)".to_string() },
-Musical { id: 396, name: "Dimples".to_string(), url: "Dimples (1935 film)".to_string() },
-Musical { id: 397, name: "Diner".to_string(), url: "Diner (1982 film)".to_string() },
-Musical { id: 398, name: "Dirty Rotten Scoundrels".to_string(), url: "Dirty Rotten Scoundrels (musical)".to_string() },
-Musical { id: 399, name: "Disaster!".to_string(), url: "Disaster! (musical)".to_string() },
-Musical { id: 400, name: "Disco Inferno".to_string(), url: "Disco Inferno (musical)".to_string() },
-Musical { id: 401, name: "Divorce Me, Darling!".to_string(), url: "Divorce Me, Darling!".to_string() },
-Musical { id: 402, name: "Dixiana".to_string(), url: "Dixiana (film)".to_string() },
-Musical { id: 403, name: "Dixie".to_string(), url: "Dixie (film)".to_string() },
-Musical { id: 404, name: "Do Black Patent Leather Shoes Really Reflect Up?".to_string(), url: "Do Black Patent Leather Shoes Really Reflect Up?".to_string() },
-Musical { id: 405, name: "Do I Hear a Waltz?".to_string(), url: "Do I Hear a Waltz?".to_string() },
-Musical { id: 406, name: "Do Re Mi".to_string(), url: "Do Re Mi (musical)".to_string() },
-Musical { id: 407, name: "Doctor Dolittle".to_string(), url: "Doctor Dolittle (musical)".to_string() },
-Musical { id: 408, name: "Doctor Zhivago".to_string(), url: "Doctor Zhivago (musical)".to_string() },
-Musical { id: 409, name: "Dogfight".to_string(), url: "Dogfight (musical)".to_string() },
-Musical { id: 410, name: "Doll Face".to_string(), url: "Doll Face".to_string() },
-Musical { id: 411, name: "Donnybrook!".to_string(), url: "Donnybrook!".to_string() },
-Musical { id: 412, name: "Don't Bother Me, I Can't Cope".to_string(), url: "Don&#39;t Bother Me, I Can&#39;t Cope".to_string() },
-Musical { id: 413, name: "Don't Play Us Cheap".to_string(), url: "Don&#39;t Play Us Cheap".to_string() },
-Musical { id: 414, name: "Doonesbury".to_string(), url: "Doonesbury (musical)".to_string() },
-Musical { id: 415, name: "Double or Nothing".to_string(), url: "Double or Nothing (1937 film)".to_string() },
-Musical { id: 416, name: "Double Trouble".to_string(), url: "Double Trouble (1967 film)".to_string() },
-Musical { id: 417, name: "Down Argentine Way".to_string(), url: "Down Argentine Way".to_string() },
-Musical { id: 418, name: "Down to Earth".to_string(), url: "Down to Earth (1947 film)".to_string() },
-Musical { id: 419, name: "Dr. Horrible's Sing-Along Blog".to_string(), url: "Dr. Horrible&#39;s Sing-Along Blog".to_string() },
-Musical { id: 420, name: "Dr. Seuss' How the Grinch Stole Christmas!".to_string(), url: "Dr. Seuss&#39; How the Grinch Stole Christmas! The Musical".to_string() },
-Musical { id: 421, name: "Dracula – Entre l'amour et la mort".to_string(), url: "Dracula – Entre l&#39;amour et la mort".to_string() },
-Musical { id: 422, name: "Dracula".to_string(), url: "Dracula, the Musical".to_string() },
-Musical { id: 423, name: "Drat! The Cat!".to_string(), url: "Drat! The Cat!".to_string() },
-Musical { id: 424, name: "Dreamboats and Petticoats".to_string(), url: "Dreamboats and Petticoats".to_string() },
-Musical { id: 425, name: "Dreamgirls".to_string(), url: "Dreamgirls".to_string() },
-Musical { id: 426, name: "Dreams Come True".to_string(), url: "Dreams Come True (1936 film)".to_string() },
-Musical { id: 427, name: "Die Drei von der Tankstelle".to_string(), url: "The Three from the Filling Station (1930 film)".to_string() },
-Musical { id: 428, name: "The Drifters Girl".to_string(), url: "The Drifters Girl".to_string() },
-Musical { id: 429, name: "DuBarry Was a Lady".to_string(), url: "DuBarry Was a Lady".to_string() },
-Musical { id: 430, name: "Duchess of Idaho".to_string(), url: "Duchess of Idaho".to_string() },
-Musical { id: 431, name: "Duck Soup".to_string(), url: "Duck Soup (1933 film)".to_string() },
-Musical { id: 432, name: "Dude".to_string(), url: "Dude (musical)".to_string() },
-Musical { id: 433, name: "Duffy's Tavern".to_string(), url: "Duffy&#39;s Tavern (film)".to_string() },
-Musical { id: 434, name: "Dumbo".to_string(), url: "Dumbo".to_string() },
-Musical { id: 435, name: "Dusty – The Original Pop Diva".to_string(), url: "Dusty – The Original Pop Diva".to_string() },
-Musical { id: 436, name: "Eadie Was a Lady".to_string(), url: "Eadie Was a Lady".to_string() },
-Musical { id: 437, name: "Earth Girls Are Easy".to_string(), url: "Earth Girls Are Easy".to_string() },
-Musical { id: 438, name: "Easter Parade".to_string(), url: "Easter Parade (film)".to_string() },
-Musical { id: 439, name: "Easter Rising".to_string(), url: "Easter Rising (musical)".to_string() },
-Musical { id: 440, name: "Easy to Love".to_string(), url: "Easy to Love (1953 film)".to_string() },
-Musical { id: 441, name: "Eating Raoul".to_string(), url: "Eating Raoul (musical)".to_string() },
-Musical { id: 442, name: "Eileen".to_string(), url: "Eileen (musical)".to_string() },
-Musical { id: 443, name: "Einbrecher".to_string(), url: "Burglars (film)".to_string() },
-Musical { id: 444, name: "Elegies".to_string(), url: "Elegies (William Finn)".to_string() },
-Musical { id: 445, name: "Elegies for Angels, Punks and Raging Queens".to_string(), url: "Elegies for Angels, Punks and Raging Queens".to_string() },
-Musical { id: 446, name: "Elf".to_string(), url: "Elf (Broadway musical)".to_string() },
-Musical { id: 447, name: "Elisabeth".to_string(), url: "Elisabeth (musical)".to_string() },
-Musical { id: 448, name: "Elixier".to_string(), url: "Elixier".to_string() },
-Musical { id: 449, name: "Elsie".to_string(), url: "A Dangerous Maid".to_string() },
-Musical { id: 450, name: "Emir".to_string(), url: "Emir (film)".to_string() },
-Musical { id: 451, name: "Emmit Otter's Jug-Band Christmas".to_string(), url: "Emmit Otter&#39;s Jug-Band Christmas (film) (page does not exist)".to_string() },
-Musical { id: 452, name: "Enchanted".to_string(), url: "Enchanted (film)".to_string() },
-Musical { id: 453, name: "Erminie".to_string(), url: "Erminie".to_string() },
-Musical { id: 454, name: "Ernest in Love".to_string(), url: "Ernest in Love".to_string() },
-Musical { id: 455, name: "Escape to Margaritaville".to_string(), url: "Escape to Margaritaville".to_string() },
-Musical { id: 456, name: "Escape from the Haltsburg Boys Choir".to_string(), url: "Escape from the Haltsburg Boys Choir (page does not exist)".to_string() },
-Musical { id: 457, name: "Eubie!".to_string(), url: "Eubie!".to_string() },
-Musical { id: 458, name: "Eugenius!".to_string(), url: "Eugenius!".to_string() },
-Musical { id: 459, name: "Evening Primrose".to_string(), url: "Evening Primrose (musical)".to_string() },
-Musical { id: 460, name: "Ever After The Musical".to_string(), url: "Ever After The Musical".to_string() },
-Musical { id: 461, name: "Ever Green".to_string(), url: "Ever Green".to_string() },
-Musical { id: 462, name: "Every Night at Eight".to_string(), url: "Every Night at Eight".to_string() },
-Musical { id: 463, name: "Everybody Sing".to_string(), url: "Everybody Sing (film)".to_string() },
-Musical { id: 464, name: "Everybody's Talking About Jamie".to_string(), url: "Everybody&#39;s Talking About Jamie".to_string() },
-Musical { id: 465, name: "Everybody's Welcome".to_string(), url: "Everybody&#39;s Welcome".to_string() },
-Musical { id: 466, name: "Everyday Rapture".to_string(), url: "Everyday Rapture".to_string() },
-Musical { id: 467, name: "Everyone Says I Love You".to_string(), url: "Everyone Says I Love You".to_string() },
-Musical { id: 468, name: "Everything I Have is Yours".to_string(), url: "Everything I Have Is Yours (film)".to_string() },
-Musical { id: 469, name: "Everything Is Rhythm".to_string(), url: "Everything Is Rhythm".to_string() },
-Musical { id: 470, name: "Evie and the Birdman".to_string(), url: "Evie and the Birdman".to_string() },
-Musical { id: 471, name: "Evil Dead".to_string(), url: "Evil Dead: The Musical".to_string() },
-Musical { id: 472, name: "Evita".to_string(), url: "Evita (musical)".to_string() },
-Musical { id: 473, name: "Fade Out - Fade In".to_string(), url: "Fade Out - Fade In".to_string() },
-Musical { id: 474, name: "F.F.F".to_string(), url: "F.F.F. (musical)".to_string() },
-Musical { id: 475, name: "Falsettoland".to_string(), url: "Falsettoland".to_string() },
-Musical { id: 476, name: "Falsettos".to_string(), url: "Falsettos".to_string() },
-Musical { id: 477, name: "Fame".to_string(), url: "Fame (musical)".to_string() },
-Musical { id: 478, name: "Fancy Nancy".to_string(), url: "Fancy Nancy the Musical".to_string() },
-Musical { id: 479, name: "FANGIRLS".to_string(), url: "Fangirls (musical)".to_string() },
-Musical { id: 480, name: "Fanny".to_string(), url: "Fanny (musical)".to_string() },
-Musical { id: 481, name: "Fellowship!".to_string(), url: "Fellowship!".to_string() },
-Musical { id: 482, name: "Fiddler on the Roof".to_string(), url: "Fiddler on the Roof".to_string() },
-Musical { id: 483, name: "Finding Nemo".to_string(), url: "Finding Nemo – The Musical".to_string() },
-Musical { id: 484, name: "Finding Neverland".to_string(), url: "Finding Neverland (musical)".to_string() },
-Musical { id: 485, name: "Fings Ain't Wot They Used T'Be".to_string(), url: "Fings Ain&#39;t Wot They Used T&#39;Be".to_string() },
-Musical { id: 486, name: "Finian's Rainbow".to_string(), url: "Finian&#39;s Rainbow".to_string() },
-Musical { id: 487, name: "Fiorello!".to_string(), url: "Fiorello!".to_string() },
-Musical { id: 488, name: "Firebringer".to_string(), url: "Firebringer".to_string() },
-Musical { id: 489, name: "First Date".to_string(), url: "First Date (musical)".to_string() },
-Musical { id: 490, name: "First Impressions".to_string(), url: "First Impressions (musical)".to_string() },
-Musical { id: 491, name: "First Lady Suite".to_string(), url: "First Lady Suite".to_string() },
-Musical { id: 492, name: "The First Queen".to_string(), url: "The First Queen".to_string() },
-Musical { id: 493, name: "Five Guys Named Moe".to_string(), url: "Five Guys Named Moe".to_string() },
-Musical { id: 494, name: "Flahooley".to_string(), url: "Flahooley".to_string() },
-Musical { id: 495, name: "Flashdance".to_string(), url: "Flashdance the Musical".to_string() },
-Musical { id: 496, name: "Flora the Red Menace".to_string(), url: "Flora the Red Menace".to_string() },
-Musical { id: 497, name: "Florodora".to_string(), url: "Florodora".to_string() },
-Musical { id: 498, name: "Flower Drum Song".to_string(), url: "Flower Drum Song".to_string() },
-Musical { id: 499, name: "Floyd Collins".to_string(), url: "Floyd Collins (musical)".to_string() },
-Musical { id: 500, name: "Flying High".to_string(), url: "Flying High (musical)".to_string() },
-Musical { id: 501, name: "Follies".to_string(), url: "Follies".to_string() },
-Musical { id: 502, name: "Follow That Girl".to_string(), url: "Follow That Girl".to_string() },
-Musical { id: 503, name: "Follow the Boys".to_string(), url: "Follow the Boys (1963 film)".to_string() },
-Musical { id: 504, name: "Follow the Girls".to_string(), url: "Follow the Girls".to_string() },
-Musical { id: 505, name: "Follow Thru".to_string(), url: "Follow Thru (musical)".to_string() },
-Musical { id: 506, name: "Footloose".to_string(), url: "Footloose (musical)".to_string() },
-Musical { id: 507, name: "Forbidden Broadway".to_string(), url: "Forbidden Broadway".to_string() },
-Musical { id: 508, name: "Forbidden Zone".to_string(), url: "Forbidden Zone".to_string() },
-Musical { id: 509, name: "Forever Plaid".to_string(), url: "Forever Plaid".to_string() },
-Musical { id: 510, name: "The Fortress of Solitude".to_string(), url: "The Fortress of Solitude (musical)".to_string() },
-Musical { id: 511, name: "Fosse".to_string(), url: "Fosse (musical)".to_string() },
-Musical { id: 512, name: "Foxy".to_string(), url: "Foxy (musical)".to_string() },
-Musical { id: 513, name: "Frankenstein".to_string(), url: "Frankenstein – A New Musical".to_string() },
-Musical { id: 514, name: "Freaky Friday".to_string(), url: "Freaky Friday (musical)".to_string() },
-Musical { id: 515, name: "Free as Air".to_string(), url: "Free as Air".to_string() },
-Musical { id: 516, name: "Freestyle Love Supreme".to_string(), url: "Freestyle Love Supreme".to_string() },
-Musical { id: 517, name: "From Here to Eternity".to_string(), url: "From Here to Eternity the Musical".to_string() },
-Musical { id: 518, name: "From Justin to Kelly".to_string(), url: "From Justin to Kelly".to_string() },
-Musical { id: 519, name: "Frozen".to_string(), url: "Frozen (musical)".to_string() },
-Musical { id: 520, name: "Frozen Fever".to_string(), url: "Frozen Fever".to_string() },
-Musical { id: 521, name: "Fun Home".to_string(), url: "Fun Home (musical)".to_string() },
-Musical { id: 522, name: "Funny Face".to_string(), url: "Funny Face (musical)".to_string() },
-Musical { id: 523, name: "Funny Girl".to_string(), url: "Funny Girl (musical)".to_string() },
-Musical { id: 524, name: "Funny Lady".to_string(), url: "Funny Lady".to_string() },
-Musical { id: 525, name: "Gay Divorce".to_string(), url: "Gay Divorce".to_string() },
-Musical { id: 526, name: "Gay Purr-ee".to_string(), url: "Gay Purr-ee".to_string() },
-Musical { id: 527, name: "Gentlemen Prefer Blondes".to_string(), url: "Gentlemen Prefer Blondes (musical)".to_string() },
-Musical { id: 528, name: "Gettin' the Band Back Together".to_string(), url: "Gettin&#39; the Band Back Together".to_string() },
-Musical { id: 529, name: "Get Up, Stand Up! The Bob Marley Musical".to_string(), url: "Get Up, Stand Up! The Bob Marley Musical".to_string() },
-Musical { id: 530, name: "Ghost".to_string(), url: "Ghost the Musical".to_string() },
-Musical { id: 531, name: "Ghost Brothers of Darkland County".to_string(), url: "Ghost Brothers of Darkland County".to_string() },
-Musical { id: 532, name: "Gigi".to_string(), url: "Gigi (musical)".to_string() },
-Musical { id: 533, name: "The Girl Behind the Gun".to_string(), url: "Kissing Time".to_string() },
-Musical { id: 534, name: "Girl Crazy".to_string(), url: "Girl Crazy".to_string() },
-Musical { id: 535, name: "The Glorious Ones".to_string(), url: "The Glorious Ones".to_string() },
-Musical { id: 536, name: "Glory Days".to_string(), url: "Glory Days (musical)".to_string() },
-Musical { id: 537, name: "Goblin Market".to_string(), url: "Goblin Market".to_string() },
-Musical { id: 538, name: "Godspell".to_string(), url: "Godspell".to_string() },
-Musical { id: 539, name: "Golden Boy".to_string(), url: "Golden Boy (musical)".to_string() },
-Musical { id: 540, name: "Golden Rainbow".to_string(), url: "Golden Rainbow (musical)".to_string() },
-Musical { id: 541, name: "Goldilocks".to_string(), url: "Goldilocks (musical)".to_string() },
-Musical { id: 542, name: "Good News".to_string(), url: "Good News (musical)".to_string() },
-Musical { id: 543, name: "Good Vibrations".to_string(), url: "Good Vibrations (musical)".to_string() },
-Musical { id: 544, name: "Goodtime Charley".to_string(), url: "Goodtime Charley".to_string() },
-Musical { id: 545, name: "Goosebumps".to_string(), url: "Goosebumps The Musical".to_string() },
-Musical { id: 546, name: "Grab Me a Gondola".to_string(), url: "Grab Me a Gondola".to_string() },
-Musical { id: 547, name: "Grand Hotel".to_string(), url: "Grand Hotel (musical)".to_string() },
-Musical { id: 548, name: "Grease".to_string(), url: "Grease (musical)".to_string() },
-Musical { id: 549, name: "Grease 2".to_string(), url: "Grease 2".to_string() },
-Musical { id: 550, name: "Great British Bake Off: The Musical".to_string(), url: "Great British Bake Off: The Musical".to_string() },
-Musical { id: 551, name: "Greenwillow".to_string(), url: "Greenwillow".to_string() },
-Musical { id: 552, name: "Grey Gardens".to_string(), url: "Grey Gardens (musical)".to_string() },
-Musical { id: 553, name: "Grimm".to_string(), url: "Grimm (musical)".to_string() },
-Musical { id: 554, name: "Grind".to_string(), url: "Grind (musical)".to_string() },
-Musical { id: 555, name: "Groundhog Day".to_string(), url: "Groundhog Day (musical)".to_string() },
-Musical { id: 556, name: "The Guy Who Didn't Like Musicals".to_string(), url: "The Guy Who Didn&#39;t Like Musicals".to_string() },
-Musical { id: 557, name: "Guys and Dolls".to_string(), url: "Guys and Dolls".to_string() },
-Musical { id: 558, name: "Gypsy".to_string(), url: "Gypsy (musical)".to_string() },
-Musical { id: 559, name: "Hadestown".to_string(), url: "Hadestown (musical)".to_string() },
-Musical { id: 560, name: "Hair".to_string(), url: "Hair (musical)".to_string() },
-Musical { id: 561, name: "Hairspray".to_string(), url: "Hairspray (musical)".to_string() },
-Musical { id: 562, name: "Half a Sixpence".to_string(), url: "Half a Sixpence".to_string() },
-Musical { id: 563, name: "Half a Sixpence".to_string(), url: "Half a Sixpence (2016 musical)".to_string() },
-Musical { id: 564, name: "Hallelujah, Baby!".to_string(), url: "Hallelujah, Baby!".to_string() },
-Musical { id: 565, name: "Hamilton".to_string(), url: "Hamilton (musical)".to_string() },
-Musical { id: 566, name: "Hamleton".to_string(), url: "Hamleton (musical) (page does not exist)".to_string() },
-Musical { id: 567, name: "Hands on a Hardbody".to_string(), url: "Hands on a Hardbody (musical)".to_string() },
-Musical { id: 568, name: "Hans Christian Andersen".to_string(), url: "Hans Christian Andersen (film)".to_string() },
-Musical { id: 569, name: "Happy Days".to_string(), url: "Happy Days (musical)".to_string() },
-Musical { id: 570, name: "Happy End".to_string(), url: "Happy End (musical)".to_string() },
-Musical { id: 571, name: "Happy Go Lovely".to_string(), url: "Happy Go Lovely".to_string() },
-Musical { id: 572, name: "Happy Hunting".to_string(), url: "Happy Hunting (musical)".to_string() },
-Musical { id: 573, name: "Harmony".to_string(), url: "Harmony (musical)".to_string() },
-Musical { id: 574, name: "Hazel Flagg".to_string(), url: "Hazel Flagg".to_string() },
-Musical { id: 575, name: "Head Over Heels".to_string(), url: "Head Over Heels (musical)".to_string() },
-Musical { id: 576, name: "Heart and Souls".to_string(), url: "Heart and Souls".to_string() },
-Musical { id: 577, name: "Heathers".to_string(), url: "Heathers: The Musical".to_string() },
-Musical { id: 578, name: "Hedwig and the Angry Inch".to_string(), url: "Hedwig and the Angry Inch (musical)".to_string() },
-Musical { id: 579, name: "Hello, Dolly!".to_string(), url: "Hello, Dolly! (musical)".to_string() },
-Musical { id: 580, name: "Hellzapoppin".to_string(), url: "Hellzapoppin (musical)".to_string() },
-Musical { id: 581, name: "Help!".to_string(), url: "Help! (film)".to_string() },
-Musical { id: 582, name: "Henry, Sweet Henry".to_string(), url: "Henry, Sweet Henry".to_string() },
-Musical { id: 583, name: "Her First Roman".to_string(), url: "Her First Roman".to_string() },
-Musical { id: 584, name: "Hercules".to_string(), url: "Hercules (1997 film)".to_string() },
-Musical { id: 585, name: "Hercules".to_string(), url: "Hercules (musical)".to_string() },
-Musical { id: 586, name: "Here Comes the Groom".to_string(), url: "Here Comes the Groom".to_string() },
-Musical { id: 587, name: "Here Lies Love".to_string(), url: "Here Lies Love (musical)".to_string() },
-Musical { id: 588, name: "Here's Love".to_string(), url: "Here&#39;s Love".to_string() },
-Musical { id: 589, name: "Charles Perrault".to_string(), url: "Charles Perrault".to_string() },
-Musical { id: 590, name: "High Button Shoes".to_string(), url: "High Button Shoes".to_string() },
-Musical { id: 591, name: "High Fidelity".to_string(), url: "High Fidelity (musical)".to_string() },
-Musical { id: 592, name: "High School Musical".to_string(), url: "High School Musical".to_string() },
-Musical { id: 593, name: "High School Musical 2".to_string(), url: "High School Musical 2".to_string() },
-Musical { id: 594, name: "High School Musical 3: Senior Year".to_string(), url: "High School Musical 3: Senior Year".to_string() },
-Musical { id: 595, name: "High School Musical on Stage!".to_string(), url: "High School Musical on Stage!".to_string() },
-Musical { id: 596, name: "High School Musical 2: On Stage!".to_string(), url: "High School Musical 2: On Stage!".to_string() },
-Musical { id: 597, name: "High Society".to_string(), url: "High Society (musical)".to_string() },
-Musical { id: 598, name: "High Spirits".to_string(), url: "High Spirits (musical)".to_string() },
-Musical { id: 599, name: "Hold Everything!".to_string(), url: "Hold Everything!".to_string() },
-Musical { id: 600, name: "Holiday Inn".to_string(), url: "Holiday Inn (musical)".to_string() },
-Musical { id: 601, name: "Holy Musical B@man!".to_string(), url: "Holy Musical B@man!".to_string() },
-Musical { id: 602, name: "Honeymoon in Vegas".to_string(), url: "Honeymoon in Vegas (musical)".to_string() },
-Musical { id: 603, name: "Honk!".to_string(), url: "Honk!".to_string() },
-Musical { id: 604, name: "Hooray for What!".to_string(), url: "Hooray for What!".to_string() },
-Musical { id: 605, name: "House of Flowers".to_string(), url: "House of Flowers (musical)".to_string() },
-Musical { id: 606, name: "How Now, Dow Jones".to_string(), url: "How Now, Dow Jones".to_string() },
-Musical { id: 607, name: "How to Eat Like a Child".to_string(), url: "How to Eat Like a Child".to_string() },
-Musical { id: 608, name: "How to Succeed in Business Without Really Trying".to_string(), url: "How to Succeed in Business Without Really Trying (musical)".to_string() },
-Musical { id: 609, name: "Hoy No Me Puedo Levantar".to_string(), url: "Hoy No Me Puedo Levantar".to_string() },
-Musical { id: 610, name: "Huckleberry Finn".to_string(), url: "Huckleberry Finn (1974 film)".to_string() },
-Musical { id: 611, name: "I and Albert".to_string(), url: "I and Albert".to_string() },
-Musical { id: 612, name: "I Can Get It for You Wholesale".to_string(), url: "I Can Get It for You Wholesale".to_string() },
-Musical { id: 613, name: "I Can't Sing!".to_string(), url: "I Can&#39;t Sing!".to_string() },
-Musical { id: 614, name: "I Do! I Do!".to_string(), url: "I Do! I Do!".to_string() },
-Musical { id: 615, name: "I Love My Wife".to_string(), url: "I Love My Wife".to_string() },
-Musical { id: 616, name: "I Love You Because".to_string(), url: "I Love You Because".to_string() },
-Musical { id: 617, name: "I Love You, You're Perfect, Now Change".to_string(), url: "I Love You, You&#39;re Perfect, Now Change".to_string() },
-Musical { id: 618, name: "I Married an Angel".to_string(), url: "I Married an Angel".to_string() },
-Musical { id: 619, name: "Idol".to_string(), url: "Idol: The Musical".to_string() },
-Musical { id: 620, name: "If/Then".to_string(), url: "If/Then".to_string() },
-Musical { id: 621, name: "Illya Darling".to_string(), url: "Illya Darling".to_string() },
-Musical { id: 622, name: "Imagine Tap!".to_string(), url: "Imagine Tap".to_string() },
-Musical { id: 623, name: "Imagine This".to_string(), url: "Imagine This".to_string() },
-Musical { id: 624, name: "In Dahomey".to_string(), url: "In Dahomey".to_string() },
-Musical { id: 625, name: "In the Green".to_string(), url: "In the Green".to_string() },
-Musical { id: 626, name: "In the Heights".to_string(), url: "In the Heights".to_string() },
-Musical { id: 627, name: "In Transit".to_string(), url: "In Transit (musical)".to_string() },
-Musical { id: 628, name: "In Trousers".to_string(), url: "In Trousers".to_string() },
-Musical { id: 629, name: "Interstella 5555: The 5tory of the 5ecret 5tar 5ystem".to_string(), url: "Interstella 5555: The 5tory of the 5ecret 5tar 5ystem".to_string() },
-Musical { id: 630, name: "Into the Light".to_string(), url: "Into the Light (musical)".to_string() },
-Musical { id: 631, name: "Into the Woods".to_string(), url: "Into the Woods".to_string() },
-Musical { id: 632, name: "Irene".to_string(), url: "Irene (musical)".to_string() },
-Musical { id: 633, name: "Irma La Douce".to_string(), url: "Irma La Douce (musical)".to_string() },
-Musical { id: 634, name: "Is There Life After High School?".to_string(), url: "Is There Life After High School?".to_string() },
-Musical { id: 635, name: "It Ain't Nothin' But the Blues".to_string(), url: "It Ain&#39;t Nothin&#39; But the Blues".to_string() },
-Musical { id: 636, name: "It Shoulda Been You".to_string(), url: "It Shoulda Been You".to_string() },
-Musical { id: 637, name: "Jackpot".to_string(), url: "Jackpot (musical)".to_string() },
-Musical { id: 638, name: "Jacques Brel is Alive and Well and Living in Paris".to_string(), url: "Jacques Brel is Alive and Well and Living in Paris".to_string() },
-Musical { id: 639, name: "Jagged Little Pill".to_string(), url: "Jagged Little Pill (musical)".to_string() },
-Musical { id: 640, name: "Jamaica".to_string(), url: "Jamaica (musical)".to_string() },
-Musical { id: 641, name: "James Joyce's The Dead".to_string(), url: "James Joyce&#39;s The Dead".to_string() },
-Musical { id: 642, name: "Jane Eyre".to_string(), url: "Jane Eyre (musical)".to_string() },
-Musical { id: 643, name: "Jekyll &amp; Hyde".to_string(), url: "Jekyll &amp; Hyde (musical)".to_string() },
-Musical { id: 644, name: "Jelly's Last Jam".to_string(), url: "Jelly&#39;s Last Jam".to_string() },
-Musical { id: 645, name: "Jerico-Jim Crow".to_string(), url: "Jerico-Jim Crow".to_string() },
-Musical { id: 646, name: "Jerome Robbins' Broadway".to_string(), url: "Jerome Robbins&#39; Broadway".to_string() },
-Musical { id: 647, name: "Jerry Springer: The Opera".to_string(), url: "Jerry Springer: The Opera".to_string() },
-Musical { id: 648, name: "Jerry's Girls".to_string(), url: "Jerry&#39;s Girls".to_string() },
-Musical { id: 649, name: "Jersey Boys".to_string(), url: "Jersey Boys".to_string() },
-Musical { id: 650, name: "Jesus Christ Superstar".to_string(), url: "Jesus Christ Superstar".to_string() },
-Musical { id: 651, name: "john &amp; jen".to_string(), url: "John &amp; Jen".to_string() },
-Musical { id: 652, name: "John Henry".to_string(), url: "John Henry (musical)".to_string() },
-Musical { id: 653, name: "Johnny Johnson".to_string(), url: "Johnny Johnson (musical)".to_string() },
-Musical { id: 654, name: "Johnny Pye and the Fool-Killer".to_string(), url: "Johnny Pye and the Fool-Killer".to_string() },
-Musical { id: 655, name: "Joseph and the Amazing Technicolor Dreamcoat".to_string(), url: "Joseph and the Amazing Technicolor Dreamcoat".to_string() },
-Musical { id: 656, name: "Jubilee".to_string(), url: "Jubilee (musical)".to_string() },
-Musical { id: 657, name: "Jumbo".to_string(), url: "Jumbo (musical)".to_string() },
-Musical { id: 658, name: "Juno".to_string(), url: "Juno (musical)".to_string() },
-Musical { id: 659, name: "Ka-Boom!".to_string(), url: "Ka-Boom!".to_string() },
-Musical { id: 660, name: "Kean".to_string(), url: "Kean (musical)".to_string() },
-Musical { id: 661, name: "Keating!".to_string(), url: "Keating!".to_string() },
-Musical { id: 662, name: "Kelly".to_string(), url: "Kelly (musical)".to_string() },
-Musical { id: 663, name: "Kimberly Akimbo".to_string(), url: "Kimberly Akimbo (musical)".to_string() },
-Musical { id: 664, name: "Kimberly Akimbo".to_string(), url: "Kimberly Akimbo".to_string() },
-Musical { id: 665, name: "King David".to_string(), url: "King David (musical)".to_string() },
-Musical { id: 666, name: "King Kong".to_string(), url: "King Kong (2013 musical)".to_string() },
-Musical { id: 667, name: "Kinky Boots".to_string(), url: "Kinky Boots (musical)".to_string() },
-Musical { id: 668, name: "Kismet".to_string(), url: "Kismet (musical)".to_string() },
-Musical { id: 669, name: "Kiss Me, Kate".to_string(), url: "Kiss Me, Kate".to_string() },
-Musical { id: 670, name: "Kiss of the Spider Woman".to_string(), url: "Kiss of the Spider Woman (musical)".to_string() },
-Musical { id: 671, name: "Kissing Time".to_string(), url: "Kissing Time".to_string() },
-Musical { id: 672, name: "Knickerbocker Holiday".to_string(), url: "Knickerbocker Holiday".to_string() },
-Musical { id: 673, name: "KPOP".to_string(), url: "KPOP (musical)".to_string() },
-Musical { id: 674, name: "Kristina från Duvemåla".to_string(), url: "Kristina från Duvemåla".to_string() },
-Musical { id: 675, name: "Kronborg: 1582".to_string(), url: "Rockabye Hamlet".to_string() },
-Musical { id: 676, name: "Kwamina".to_string(), url: "Kwamina".to_string() },
-Musical { id: 677, name: "La La Land".to_string(), url: "La La Land (film)".to_string() },
-Musical { id: 678, name: "Labyrinth".to_string(), url: "Labyrinth (1986 film)".to_string() },
-Musical { id: 679, name: "Lady and the Tramp".to_string(), url: "Lady and the Tramp".to_string() },
-Musical { id: 680, name: "Lady in the Dark".to_string(), url: "Lady in the Dark".to_string() },
-Musical { id: 681, name: "Lady, Be Good".to_string(), url: "Lady, Be Good (musical)".to_string() },
-Musical { id: 682, name: "Laughing Room Only".to_string(), url: "Laughing Room Only".to_string() },
-Musical { id: 683, name: "Leader of the Pack".to_string(), url: "Leader of the Pack (musical)".to_string() },
-Musical { id: 684, name: "Leap of Faith".to_string(), url: "Leap of Faith (musical)".to_string() },
-Musical { id: 685, name: "Leben ohne Chris".to_string(), url: "Leben ohne Chris".to_string() },
-Musical { id: 686, name: "Legally Blonde".to_string(), url: "Legally Blonde (musical)".to_string() },
-Musical { id: 687, name: "Legs Diamond".to_string(), url: "Legs Diamond (musical)".to_string() },
-Musical { id: 688, name: "Lend Me a Tenor".to_string(), url: "Lend Me A Tenor (musical)".to_string() },
-Musical { id: 689, name: "Lennon".to_string(), url: "Lennon (musical)".to_string() },
-Musical { id: 690, name: "Leonard Sillman's New Faces of 1952".to_string(), url: "New Faces of 1952".to_string() },
-Musical { id: 691, name: "Lestat".to_string(), url: "Lestat (musical)".to_string() },
-Musical { id: 692, name: "Let It Ride".to_string(), url: "Let It Ride (musical)".to_string() },
-Musical { id: 693, name: "Let My People Come".to_string(), url: "Let My People Come".to_string() },
-Musical { id: 694, name: "Letters from Lehrer".to_string(), url: "Letters from Lehrer".to_string() },
-Musical { id: 695, name: "Liberty: The Siege of The Alamo".to_string(), url: "Liberty: The Siege of The Alamo (page does not exist)".to_string() },
-Musical { id: 696, name: "Life Begins at 8:40".to_string(), url: "Life Begins at 8:40".to_string() },
-Musical { id: 697, name: "Li'l Abner".to_string(), url: "Li&#39;l Abner (musical)".to_string() },
-Musical { id: 698, name: "The Lion King 1½".to_string(), url: "The Lion King 1½".to_string() },
-Musical { id: 699, name: "The Lion King II: Simba's Pride".to_string(), url: "The Lion King II: Simba&#39;s Pride".to_string() },
-Musical { id: 700, name: "Little by Little".to_string(), url: "Little by Little (musical)".to_string() },
-Musical { id: 701, name: "Little Fish".to_string(), url: "Little Fish (musical)".to_string() },
-Musical { id: 702, name: "Little Johnny Jones".to_string(), url: "Little Johnny Jones".to_string() },
-Musical { id: 703, name: "Little Mary Sunshine".to_string(), url: "Little Mary Sunshine".to_string() },
-Musical { id: 704, name: "Little Me".to_string(), url: "Little Me (musical)".to_string() },
-Musical { id: 705, name: "Little Miss Sunshine".to_string(), url: "Little Miss Sunshine (musical)".to_string() },
-Musical { id: 706, name: "Little Nemo: Adventures in Slumberland".to_string(), url: "Little Nemo: Adventures in Slumberland".to_string() },
-Musical { id: 707, name: "Little Shop of Horrors".to_string(), url: "Little Shop of Horrors (musical)".to_string() },
-Musical { id: 708, name: "Little Tramp".to_string(), url: "Little Tramp".to_string() },
-Musical { id: 709, name: "Little Women".to_string(), url: "Little Women (musical)".to_string() },
-Musical { id: 710, name: "Lock Up Your Daughters".to_string(), url: "Lock Up Your Daughters (musical)".to_string() },
-Musical { id: 711, name: "London Calling!".to_string(), url: "London Calling!".to_string() },
-Musical { id: 712, name: "London Road".to_string(), url: "London Road (musical)".to_string() },
-Musical { id: 713, name: "Lone Star Love".to_string(), url: "Lone Star Love".to_string() },
-Musical { id: 714, name: "Look to the Lilies".to_string(), url: "Look to the Lilies".to_string() },
-Musical { id: 715, name: "Lord of the Dance".to_string(), url: "Lord of the Dance (musical)".to_string() },
-Musical { id: 716, name: "The Lord of the Rings".to_string(), url: "Lord of the Rings (musical)".to_string() },
-Musical { id: 717, name: "Lorelei".to_string(), url: "Lorelei (musical)".to_string() },
-Musical { id: 718, name: "Loserville".to_string(), url: "Loserville".to_string() },
-Musical { id: 719, name: "Lost in the Stars".to_string(), url: "Lost in the Stars".to_string() },
-Musical { id: 720, name: "Louisiana Purchase".to_string(), url: "Louisiana Purchase (musical)".to_string() },
-Musical { id: 721, name: "Love from Judy".to_string(), url: "Love from Judy".to_string() },
-Musical { id: 722, name: "Love Life".to_string(), url: "Love Life (musical)".to_string() },
-Musical { id: 723, name: "Love Never Dies".to_string(), url: "Love Never Dies (musical)".to_string() },
-Musical { id: 724, name: "Love Story".to_string(), url: "Love Story (musical)".to_string() },
-Musical { id: 725, name: "Love, Janis".to_string(), url: "Love, Janis".to_string() },
-Musical { id: 726, name: "LoveMusik".to_string(), url: "LoveMusik".to_string() },
-Musical { id: 727, name: "Lucky Guy".to_string(), url: "Lucky Guy (musical)".to_string() },
-Musical { id: 728, name: "Lucky Stiff".to_string(), url: "Lucky Stiff".to_string() },
-Musical { id: 729, name: "Ludwig II".to_string(), url: "Ludwig II (musical)".to_string() },
-Musical { id: 730, name: "Lust 'n Rust".to_string(), url: "Lust &#39;n Rust".to_string() },
-Musical { id: 731, name: "Lysistrata Jones".to_string(), url: "Lysistrata Jones".to_string() },
-Musical { id: 732, name: "Mack &amp; Mabel".to_string(), url: "Mack &amp; Mabel".to_string() },
-Musical { id: 733, name: "Made in Dagenham".to_string(), url: "Made in Dagenham (musical)".to_string() },
-Musical { id: 734, name: "Maggie May".to_string(), url: "Maggie May (musical)".to_string() },
-Musical { id: 735, name: "The Magic Basket".to_string(), url: "The Magic Basket".to_string() },
-Musical { id: 736, name: "Magic Gum Tree".to_string(), url: "Magic Gum Tree".to_string() },
-Musical { id: 737, name: "Make a Wish".to_string(), url: "Make a Wish (musical)".to_string() },
-Musical { id: 738, name: "Make Me a Song".to_string(), url: "Make Me a Song (musical)".to_string() },
-Musical { id: 739, name: "Making Tracks".to_string(), url: "Making Tracks".to_string() },
-Musical { id: 740, name: "Mame".to_string(), url: "Mame (musical)".to_string() },
-Musical { id: 741, name: "Mamma Mia!".to_string(), url: "Mamma Mia! (musical)".to_string() },
-Musical { id: 742, name: "Mamma Mia! Here We Go Again".to_string(), url: "Mamma Mia! Here We Go Again".to_string() },
-Musical { id: 743, name: "Mam'zelle Champagne".to_string(), url: "Mam&#39;zelle Champagne".to_string() },
-Musical { id: 744, name: "Man of La Mancha".to_string(), url: "Man of La Mancha".to_string() },
-Musical { id: 745, name: "A Man of No Importance".to_string(), url: "A Man of No Importance (musical)".to_string() },
-Musical { id: 746, name: "March of the Falsettos".to_string(), url: "March of the Falsettos".to_string() },
-Musical { id: 747, name: "Margie".to_string(), url: "Margie (1946 film)".to_string() },
-Musical { id: 748, name: "Marguerite".to_string(), url: "Marguerite (musical)".to_string() },
-Musical { id: 749, name: "Marie Christine".to_string(), url: "Marie Christine".to_string() },
-Musical { id: 750, name: "Marinka".to_string(), url: "Marinka (operetta)".to_string() },
-Musical { id: 751, name: "Marry Me a Little".to_string(), url: "Marry Me a Little (musical)".to_string() },
-Musical { id: 752, name: "Martin Guerre".to_string(), url: "Martin Guerre (musical)".to_string() },
-Musical { id: 753, name: "Mary Poppins".to_string(), url: "Mary Poppins (musical)".to_string() },
-Musical { id: 754, name: "Mary Poppins Returns".to_string(), url: "Mary Poppins Returns".to_string() },
-Musical { id: 755, name: "Matador".to_string(), url: "Matador (English musical)".to_string() },
-Musical { id: 756, name: "Matilda".to_string(), url: "Matilda the Musical".to_string() },
-Musical { id: 757, name: "Maytime".to_string(), url: "Maytime (musical)".to_string() },
-Musical { id: 758, name: "Me and Juliet".to_string(), url: "Me and Juliet".to_string() },
-Musical { id: 759, name: "Me and My Dick".to_string(), url: "Me and My Dick".to_string() },
-Musical { id: 760, name: "Me and My Girl".to_string(), url: "Me and My Girl".to_string() },
-Musical { id: 761, name: "Mean Girls".to_string(), url: "Mean Girls (musical)".to_string() },
-Musical { id: 762, name: "Meet Me in St. Louis".to_string(), url: "Meet Me in St. Louis (musical)".to_string() },
-Musical { id: 763, name: "Meet the Feebles".to_string(), url: "Meet the Feebles".to_string() },
-Musical { id: 764, name: "Memphis".to_string(), url: "Memphis (musical)".to_string() },
-Musical { id: 765, name: "Menopause".to_string(), url: "Menopause The Musical".to_string() },
-Musical { id: 766, name: "Merlin".to_string(), url: "Merlin (musical)".to_string() },
-Musical { id: 767, name: "Merrily We Roll Along".to_string(), url: "Merrily We Roll Along (musical)".to_string() },
-Musical { id: 768, name: "Metropolis".to_string(), url: "Metropolis (musical)".to_string() },
-Musical { id: 769, name: "Mexican Hayride".to_string(), url: "Mexican Hayride (musical)".to_string() },
-Musical { id: 770, name: "Millennials".to_string(), url: "Millennials (musical)".to_string() },
-Musical { id: 771, name: "Millennium Dome Show".to_string(), url: "Millennium Dome Show".to_string() },
-Musical { id: 772, name: "Milk and Honey".to_string(), url: "Milk and Honey (musical)".to_string() },
-Musical { id: 773, name: "Million Dollar Quartet".to_string(), url: "Million Dollar Quartet (musical)".to_string() },
-Musical { id: 774, name: "Les Misérables".to_string(), url: "Les Misérables (musical)".to_string() },
-Musical { id: 775, name: "Les Misérables".to_string(), url: "Les Misérables (musical)".to_string() },
-Musical { id: 776, name: "Miss 1917".to_string(), url: "Miss 1917".to_string() },
-Musical { id: 777, name: "Miss Liberty".to_string(), url: "Miss Liberty".to_string() },
-Musical { id: 778, name: "Miss Saigon".to_string(), url: "Miss Saigon".to_string() },
-Musical { id: 779, name: "MJ the Musical".to_string(), url: "MJ the Musical".to_string() },
-Musical { id: 780, name: "Moana".to_string(), url: "Moana (2016 film)".to_string() },
-Musical { id: 781, name: "Moby Dick".to_string(), url: "Moby Dick (musical)".to_string() },
-Musical { id: 782, name: "Monkey Business".to_string(), url: "Monkey Business (The Musical) (page does not exist)".to_string() },
-Musical { id: 783, name: "Moon Landing".to_string(), url: "Moon Landing (music drama)".to_string() },
-Musical { id: 784, name: "More Than You Deserve".to_string(), url: "More Than You Deserve".to_string() },
-Musical { id: 785, name: "Motown: The Musical".to_string(), url: "Motown: The Musical".to_string() },
-Musical { id: 786, name: "Moulin Rouge!".to_string(), url: "Moulin Rouge! (musical)".to_string() },
-Musical { id: 787, name: "Movin' Out".to_string(), url: "Movin&#39; Out (musical)".to_string() },
-Musical { id: 788, name: "Mozart!".to_string(), url: "Mozart!".to_string() },
-Musical { id: 789, name: "Mozart, l'opéra rock".to_string(), url: "Mozart, l&#39;opéra rock".to_string() },
-Musical { id: 790, name: "Mr. Cinders".to_string(), url: "Mr. Cinders".to_string() },
-Musical { id: 791, name: "Mr. President".to_string(), url: "Mr. President (musical)".to_string() },
-Musical { id: 792, name: "Mr. Wonderful".to_string(), url: "Mr. Wonderful (musical)".to_string() },
-Musical { id: 793, name: "Mrs Henderson Presents".to_string(), url: "Mrs Henderson Presents (musical)".to_string() },
-Musical { id: 794, name: "Mrs. Santa Claus".to_string(), url: "Mrs. Santa Claus".to_string() },
-Musical { id: 795, name: "Much Ado".to_string(), url: "Much Ado".to_string() },
-Musical { id: 796, name: "Mulan".to_string(), url: "Mulan (1998 film)".to_string() },
-Musical { id: 797, name: "Mulan II".to_string(), url: "Mulan II".to_string() },
-Musical { id: 798, name: "Murder Ballad".to_string(), url: "Murder Ballad (musical)".to_string() },
-Musical { id: 799, name: "Murder for Two".to_string(), url: "Murder for Two".to_string() },
-Musical { id: 800, name: "Muriel's Wedding (musical)".to_string(), url: "Muriel&#39;s Wedding (musical)".to_string() },
-Musical { id: 801, name: "My Fair Lady".to_string(), url: "My Fair Lady".to_string() },
-Musical { id: 802, name: "My Favorite Year".to_string(), url: "My Favorite Year (musical)".to_string() },
-Musical { id: 803, name: "My Life With Albertine".to_string(), url: "My Life With Albertine".to_string() },
-Musical { id: 804, name: "My Little Pony: The Movie".to_string(), url: "My Little Pony: The Movie (2017 film)".to_string() },
-Musical { id: 805, name: "My One and Only".to_string(), url: "My One and Only (musical)".to_string() },
-Musical { id: 806, name: "My Scary Girl".to_string(), url: "My Scary Girl".to_string() },
-Musical { id: 807, name: "My Son Pinocchio".to_string(), url: "My Son Pinocchio".to_string() },
-Musical { id: 808, name: "The Mystery of Edwin Drood".to_string(), url: "Drood".to_string() },
-Musical { id: 809, name: "Napoleon".to_string(), url: "Napoleon (musical)".to_string() },
-Musical { id: 810, name: "Narnia".to_string(), url: "Adaptations of The Chronicles of Narnia".to_string() },
-Musical { id: 811, name: "Natasha, Pierre &amp; The Great Comet of 1812".to_string(), url: "Natasha, Pierre &amp; The Great Comet of 1812".to_string() },
-Musical { id: 812, name: "Nativity!".to_string(), url: "Nativity! The Musical".to_string() },
-Musical { id: 813, name: "Ned Kelly".to_string(), url: "Ned Kelly (1970 film)".to_string() },
-Musical { id: 814, name: "Netru, Indru, Naalai".to_string(), url: "Netru, Indru, Naalai".to_string() },
-Musical { id: 815, name: "Never Forget".to_string(), url: "Never Forget (musical)".to_string() },
-Musical { id: 816, name: "Never Gonna Dance".to_string(), url: "Never Gonna Dance".to_string() },
-Musical { id: 817, name: "Nevermore: The Imaginary Life and Mysterious Death of Edgar Allan Poe".to_string(), url: "Nevermore: The Imaginary Life and Mysterious Death of Edgar Allan Poe".to_string() },
-Musical { id: 818, name: "New Faces of 1952".to_string(), url: "New Faces of 1952".to_string() },
-Musical { id: 819, name: "New Girl in Town".to_string(), url: "New Girl in Town".to_string() },
-Musical { id: 820, name: "Newsical".to_string(), url: "Newsical".to_string() },
-Musical { id: 821, name: "Newsies".to_string(), url: "Newsies (musical)".to_string() },
-Musical { id: 822, name: "Next to Normal".to_string(), url: "Next to Normal".to_string() },
-Musical { id: 823, name: "Nice Work If You Can Get It".to_string(), url: "Nice Work If You Can Get It (musical)".to_string() },
-Musical { id: 824, name: "A Night with Janis Joplin".to_string(), url: "A Night with Janis Joplin".to_string() },
-Musical { id: 825, name: "Nightingale".to_string(), url: "Nightingale (musical)".to_string() },
-Musical { id: 826, name: "Nine".to_string(), url: "Nine (musical)".to_string() },
-Musical { id: 827, name: "No Strings".to_string(), url: "No Strings".to_string() },
-Musical { id: 828, name: "No, No, Nanette".to_string(), url: "No, No, Nanette".to_string() },
-Musical { id: 829, name: "Nobody's Boy".to_string(), url: "Nobody&#39;s Boy (musical)".to_string() },
-Musical { id: 830, name: "Nord-Ost".to_string(), url: "Nord-Ost".to_string() },
-Musical { id: 831, name: "Nosferatu the Vampire".to_string(), url: "Nosferatu The Vampire (musical)".to_string() },
-Musical { id: 832, name: "Notre-Dame de Paris".to_string(), url: "Notre-Dame de Paris (musical)".to_string() },
-Musical { id: 833, name: "Now Is the Time for All Good Men".to_string(), url: "Now Is the Time for All Good Men".to_string() },
-Musical { id: 834, name: "Now. Here. This.".to_string(), url: "Now. Here. This.".to_string() },
-Musical { id: 835, name: "Nunsense".to_string(), url: "Nunsense".to_string() },
-Musical { id: 836, name: "Nymph Errant".to_string(), url: "Nymph Errant".to_string() },
-Musical { id: 837, name: "Of Thee I Sing".to_string(), url: "Of Thee I Sing".to_string() },
-Musical { id: 838, name: "Oh, Boy!".to_string(), url: "Oh, Boy! (musical)".to_string() },
-Musical { id: 839, name: "Oh! Calcutta!".to_string(), url: "Oh! Calcutta!".to_string() },
-Musical { id: 840, name: "Oh, Captain!".to_string(), url: "Oh, Captain!".to_string() },
-Musical { id: 841, name: "Oh, Kay!".to_string(), url: "Oh, Kay!".to_string() },
-Musical { id: 842, name: "Oh, What a Lovely War!".to_string(), url: "Oh, What a Lovely War!".to_string() },
-Musical { id: 843, name: "Oil City Symphony".to_string(), url: "Oil City Symphony".to_string() },
-Musical { id: 844, name: "Oklahoma!".to_string(), url: "Oklahoma!".to_string() },
-Musical { id: 845, name: "Olaf's Frozen Adventure".to_string(), url: "Olaf&#39;s Frozen Adventure".to_string() },
-Musical { id: 846, name: "Oliver &amp; Company".to_string(), url: "Oliver &amp; Company".to_string() },
-Musical { id: 847, name: "Oliver!".to_string(), url: "Oliver!".to_string() },
-Musical { id: 848, name: "Olympus on My Mind".to_string(), url: "Olympus on My Mind".to_string() },
-Musical { id: 849, name: "On a Clear Day You Can See Forever".to_string(), url: "On a Clear Day You Can See Forever".to_string() },
-Musical { id: 850, name: "On the Record".to_string(), url: "On the Record (musical)".to_string() },
-Musical { id: 851, name: "On the Town".to_string(), url: "On the Town (musical)".to_string() },
-Musical { id: 852, name: "On the Twentieth Century".to_string(), url: "On the Twentieth Century".to_string() },
-Musical { id: 853, name: "On Your Feet!".to_string(), url: "On Your Feet!".to_string() },
-Musical { id: 854, name: "On Your Toes".to_string(), url: "On Your Toes".to_string() },
-Musical { id: 855, name: "Once".to_string(), url: "Once (musical)".to_string() },
-Musical { id: 856, name: "Once on This Island".to_string(), url: "Once on This Island".to_string() },
-Musical { id: 857, name: "Once Upon a Mattress".to_string(), url: "Once Upon a Mattress".to_string() },
-Musical { id: 858, name: "Once Upon a One More Time".to_string(), url: "Once Upon a One More Time".to_string() },
-Musical { id: 859, name: "Once Upon a Time at the Adelphi".to_string(), url: "Once Upon a Time at the Adelphi".to_string() },
-Musical { id: 860, name: "One and One".to_string(), url: "One and One (musical)".to_string() },
-Musical { id: 861, name: "One Touch of Venus".to_string(), url: "One Touch of Venus".to_string() },
-Musical { id: 862, name: "Only Fools and Horses The Musical".to_string(), url: "Only Fools and Horses The Musical".to_string() },
-Musical { id: 863, name: "Ordinary Days".to_string(), url: "Ordinary Days".to_string() },
-Musical { id: 864, name: "Our House".to_string(), url: "Our House (musical)".to_string() },
-Musical { id: 865, name: "Our Miss Gibbs".to_string(), url: "Our Miss Gibbs".to_string() },
-Musical { id: 866, name: "Out of This World".to_string(), url: "Out of This World (musical)".to_string() },
-Musical { id: 867, name: "Over Here!".to_string(), url: "Over Here!".to_string() },
-Musical { id: 868, name: "Pacific 1860".to_string(), url: "Pacific 1860".to_string() },
-Musical { id: 869, name: "Pacific Overtures".to_string(), url: "Pacific Overtures".to_string() },
-Musical { id: 870, name: "Paint Your Wagon".to_string(), url: "Paint Your Wagon (musical)".to_string() },
-Musical { id: 871, name: "Pal Joey".to_string(), url: "Pal Joey (musical)".to_string() },
-Musical { id: 872, name: "Panama Hattie".to_string(), url: "Panama Hattie".to_string() },
-Musical { id: 873, name: "Parade".to_string(), url: "Parade (musical)".to_string() },
-Musical { id: 874, name: "Paramour".to_string(), url: "Paramour (Cirque du Soleil)".to_string() },
-Musical { id: 875, name: "Paris".to_string(), url: "Paris (1928 musical)".to_string() },
-Musical { id: 876, name: "Paris".to_string(), url: "Paris (2003 musical)".to_string() },
-Musical { id: 877, name: "Passing Strange".to_string(), url: "Passing Strange (musical)".to_string() },
-Musical { id: 878, name: "Passion".to_string(), url: "Passion (musical)".to_string() },
-Musical { id: 879, name: "Pennies from Heaven".to_string(), url: "Pennies from Heaven (1981 film)".to_string() },
-Musical { id: 880, name: "Perchance to Dream".to_string(), url: "Perchance to Dream (musical)".to_string() },
-Musical { id: 881, name: "Perfect Harmony".to_string(), url: "Perfect Harmony (musical)".to_string() },
-Musical { id: 882, name: "Peter Pan".to_string(), url: "Peter Pan (1950 musical)".to_string() },
-Musical { id: 883, name: "Peter Pan".to_string(), url: "Peter Pan (1953 film)".to_string() },
-Musical { id: 884, name: "Peter Pan".to_string(), url: "Peter Pan (1954 musical)".to_string() },
-Musical { id: 885, name: "Peter Pan: A Musical Adventure".to_string(), url: "Peter Pan: A Musical Adventure".to_string() },
-Musical { id: 886, name: "Pete's Dragon".to_string(), url: "Pete&#39;s Dragon (1977 film)".to_string() },
-Musical { id: 887, name: "Phantom of the Opera".to_string(), url: "Phantom of the Opera (1976 musical)".to_string() },
-Musical { id: 888, name: "Phi-Phi".to_string(), url: "Phi-Phi".to_string() },
-Musical { id: 889, name: "Pickwick".to_string(), url: "Pickwick (musical)".to_string() },
-Musical { id: 890, name: "Pieces of Eight".to_string(), url: "Pieces of Eight (1985 musical)".to_string() },
-Musical { id: 891, name: "Pink Floyd – The Wall".to_string(), url: "Pink Floyd – The Wall".to_string() },
-Musical { id: 892, name: "Pinocchio".to_string(), url: "Pinocchio (1940 film)".to_string() },
-Musical { id: 893, name: "Pins and Needles".to_string(), url: "Pins and Needles".to_string() },
-Musical { id: 894, name: "Pipe Dream".to_string(), url: "Pipe Dream (musical)".to_string() },
-Musical { id: 895, name: "Pippin".to_string(), url: "Pippin (musical)".to_string() },
-Musical { id: 896, name: "Pitch Perfect".to_string(), url: "Pitch Perfect".to_string() },
-Musical { id: 897, name: "Pitch Perfect 2".to_string(), url: "Pitch Perfect 2".to_string() },
-Musical { id: 898, name: "Pitch Perfect 3".to_string(), url: "Pitch Perfect 3".to_string() },
-Musical { id: 899, name: "Plain and Fancy".to_string(), url: "Plain and Fancy".to_string() },
-Musical { id: 900, name: "Platinum".to_string(), url: "Platinum (musical)".to_string() },
-Musical { id: 901, name: "Play On!".to_string(), url: "Play On!".to_string() },
-Musical { id: 902, name: "Pocahontas".to_string(), url: "Pocahontas (1995 film)".to_string() },
-Musical { id: 903, name: "Pokémon Live!".to_string(), url: "Pokémon Live!".to_string() },
-Musical { id: 904, name: "Poor Little Rich Girl".to_string(), url: "Poor Little Rich Girl (1936 film)".to_string() },
-Musical { id: 905, name: "Popeye".to_string(), url: "Popeye (film)".to_string() },
-Musical { id: 906, name: "Porgy and Bess".to_string(), url: "Porgy and Bess".to_string() },
-Musical { id: 907, name: "Portofino".to_string(), url: "Portofino (musical)".to_string() },
-Musical { id: 908, name: "Pretty Woman".to_string(), url: "Pretty Woman: The Musical".to_string() },
-Musical { id: 909, name: "Pride and Prejudice".to_string(), url: "Pride and Prejudice (musical)".to_string() },
-Musical { id: 910, name: "Prince of Broadway".to_string(), url: "Prince of Broadway".to_string() },
-Musical { id: 911, name: "The Princess and the Frog".to_string(), url: "The Princess and the Frog".to_string() },
-Musical { id: 912, name: "Priscilla, Queen of the Desert".to_string(), url: "Priscilla, Queen of the Desert (musical)".to_string() },
-Musical { id: 913, name: "Promenade".to_string(), url: "Promenade (musical)".to_string() },
-Musical { id: 914, name: "Promises, Promises".to_string(), url: "Promises, Promises (musical)".to_string() },
-Musical { id: 915, name: "Pump Boys and Dinettes".to_string(), url: "Pump Boys and Dinettes".to_string() },
-Musical { id: 916, name: "Purlie".to_string(), url: "Purlie".to_string() },
-Musical { id: 917, name: "Puteri Gunung Ledang".to_string(), url: "Puteri Gunung Ledang (musical)".to_string() },
-Musical { id: 918, name: "Quadrophenia".to_string(), url: "Quadrophenia (musical)".to_string() },
-Musical { id: 919, name: "Quilters".to_string(), url: "Quilters (musical)".to_string() },
-Musical { id: 920, name: "Rachael Lily Rosenbloom (And Don't You Ever Forget It)".to_string(), url: "Rachael Lily Rosenbloom (And Don&#39;t You Ever Forget It)".to_string() },
-Musical { id: 921, name: "Raggedy Ann".to_string(), url: "Raggedy Ann (musical)".to_string() },
-Musical { id: 922, name: "Raggedy Ann &amp; Andy: A Musical Adventure".to_string(), url: "Raggedy Ann &amp; Andy: A Musical Adventure".to_string() },
-Musical { id: 923, name: "Rags".to_string(), url: "Rags (musical)".to_string() },
-Musical { id: 924, name: "Ragtime".to_string(), url: "Ragtime (musical)".to_string() },
-Musical { id: 925, name: "Raise Your Voice".to_string(), url: "Raise Your Voice".to_string() },
-Musical { id: 926, name: "Raisin".to_string(), url: "Raisin (musical)".to_string() },
-Musical { id: 927, name: "Randy Newman's Faust".to_string(), url: "Randy Newman&#39;s Faust".to_string() },
-Musical { id: 928, name: "Ratatouille the Musical".to_string(), url: "Ratatouille the Musical".to_string() },
-Musical { id: 929, name: "Rebecca".to_string(), url: "Rebecca (musical)".to_string() },
-Musical { id: 930, name: "Red, Hot and Blue".to_string(), url: "Red, Hot and Blue".to_string() },
-Musical { id: 931, name: "Redhead".to_string(), url: "Redhead (musical)".to_string() },
-Musical { id: 932, name: "Reefer Madness".to_string(), url: "Reefer Madness (musical)".to_string() },
-Musical { id: 933, name: "Rent".to_string(), url: "Rent (musical)".to_string() },
-Musical { id: 934, name: "Repo! The Genetic Opera".to_string(), url: "Repo! The Genetic Opera".to_string() },
-Musical { id: 935, name: "Return to the Forbidden Planet".to_string(), url: "Return to the Forbidden Planet".to_string() },
-Musical { id: 936, name: "Rex".to_string(), url: "Rex (musical)".to_string() },
-Musical { id: 937, name: "Ride the Cyclone".to_string(), url: "Ride the Cyclone".to_string() },
-Musical { id: 938, name: "The Rink".to_string(), url: "The Rink (musical)".to_string() },
-Musical { id: 939, name: "Road Show".to_string(), url: "Road Show (musical)".to_string() },
-Musical { id: 940, name: "Road to Morocco".to_string(), url: "Road to Morocco".to_string() },
-Musical { id: 941, name: "Road to Singapore".to_string(), url: "Road to Singapore".to_string() },
-Musical { id: 942, name: "Road to Utopia".to_string(), url: "Road to Utopia".to_string() },
-Musical { id: 943, name: "Road to Zanzibar".to_string(), url: "Road to Zanzibar".to_string() },
-Musical { id: 944, name: "Robert and Elizabeth".to_string(), url: "Robert and Elizabeth".to_string() },
-Musical { id: 945, name: "Roberta".to_string(), url: "Roberta".to_string() },
-Musical { id: 946, name: "Robinson Crusoe".to_string(), url: "Robinson Crusoe (musical)".to_string() },
-Musical { id: 947, name: "Rock of Ages".to_string(), url: "Rock of Ages (musical)".to_string() },
-Musical { id: 948, name: "Rockabye Hamlet".to_string(), url: "Rockabye Hamlet".to_string() },
-Musical { id: 949, name: "Rocky".to_string(), url: "Rocky the Musical".to_string() },
-Musical { id: 950, name: "Roman Holiday".to_string(), url: "Roman Holiday (musical)".to_string() },
-Musical { id: 951, name: "Romance/Romance".to_string(), url: "Romance/Romance".to_string() },
-Musical { id: 952, name: "Roméo et Juliette, de la Haine à l'Amour".to_string(), url: "Roméo et Juliette, de la Haine à l&#39;Amour".to_string() },
-Musical { id: 953, name: "Rosalie".to_string(), url: "Rosalie (musical)".to_string() },
-Musical { id: 954, name: "Rose-Marie".to_string(), url: "Rose-Marie".to_string() },
-Musical { id: 955, name: "Rover Dangerfield".to_string(), url: "Rover Dangerfield".to_string() },
-Musical { id: 956, name: "Royal Wedding".to_string(), url: "Royal Wedding".to_string() },
-Musical { id: 957, name: "Rudolf".to_string(), url: "Rudolf (musical)".to_string() },
-Musical { id: 958, name: "Runaways".to_string(), url: "Runaways (musical)".to_string() },
-Musical { id: 959, name: "Sail Away".to_string(), url: "Sail Away (musical)".to_string() },
-Musical { id: 960, name: "Salad Days".to_string(), url: "Salad Days (musical)".to_string() },
-Musical { id: 961, name: "Sally".to_string(), url: "Sally (musical)".to_string() },
-Musical { id: 962, name: "Saludos Amigos".to_string(), url: "Saludos Amigos".to_string() },
-Musical { id: 963, name: "Salvation".to_string(), url: "Salvation (musical)".to_string() },
-Musical { id: 964, name: "San Francisco".to_string(), url: "San Francisco (1936 film)".to_string() },
-Musical { id: 965, name: "Sarafina!".to_string(), url: "Sarafina! (musical)".to_string() },
-Musical { id: 966, name: "Saratoga".to_string(), url: "Saratoga (musical)".to_string() },
-Musical { id: 967, name: "Saturday Night".to_string(), url: "Saturday Night (musical)".to_string() },
-Musical { id: 968, name: "Saturday Night Fever".to_string(), url: "Saturday Night Fever (musical)".to_string() },
-Musical { id: 969, name: "Saucy Jack and the Space Vixens".to_string(), url: "Saucy Jack and the Space Vixens".to_string() },
-Musical { id: 970, name: "Saved".to_string(), url: "Saved (musical)".to_string() },
-Musical { id: 971, name: "Say It with Songs".to_string(), url: "Say It with Songs".to_string() },
-Musical { id: 972, name: "Say When".to_string(), url: "Say When (musical)".to_string() },
-Musical { id: 973, name: "Say, Darling".to_string(), url: "Say, Darling".to_string() },
-Musical { id: 974, name: "Scandalous".to_string(), url: "Scandalous: The Life and Trials of Aimee Semple McPherson".to_string() },
-Musical { id: 975, name: "School of Rock".to_string(), url: "School of Rock (musical)".to_string() },
-Musical { id: 976, name: "Schwestern im Geiste".to_string(), url: "Schwestern im Geiste".to_string() },
-Musical { id: 977, name: "Scrooge".to_string(), url: "Scrooge (1970 film)".to_string() },
-Musical { id: 978, name: "Second Chance".to_string(), url: "Second Chance (musical)".to_string() },
-Musical { id: 979, name: "See What I Wanna See".to_string(), url: "See What I Wanna See".to_string() },
-Musical { id: 980, name: "Seesaw".to_string(), url: "Seesaw (musical)".to_string() },
-Musical { id: 981, name: "Seussical".to_string(), url: "Seussical".to_string() },
-Musical { id: 982, name: "Seven Brides for Seven Brothers".to_string(), url: "Seven Brides for Seven Brothers (musical)".to_string() },
-Musical { id: 983, name: "Sgt. Pepper's Lonely Hearts Club Band".to_string(), url: "Sgt. Pepper&#39;s Lonely Hearts Club Band (film)".to_string() },
-Musical { id: 984, name: "Shangri-La".to_string(), url: "Shangri-La (musical)".to_string() },
-Musical { id: 985, name: "Sharpay's Fabulous Adventure".to_string(), url: "Sharpay&#39;s Fabulous Adventure".to_string() },
-Musical { id: 986, name: "She Loves Me".to_string(), url: "She Loves Me".to_string() },
-Musical { id: 987, name: "Shenandoah".to_string(), url: "Shenandoah (musical)".to_string() },
-Musical { id: 988, name: "Shinbone Alley".to_string(), url: "Shinbone Alley".to_string() },
-Musical { id: 989, name: "Shock Treatment".to_string(), url: "Shock Treatment".to_string() },
-Musical { id: 990, name: "Shockheaded Peter".to_string(), url: "Shockheaded Peter (musical)".to_string() },
-Musical { id: 991, name: "Shout! The Mod Musical".to_string(), url: "Shout! The Mod Musical".to_string() },
-Musical { id: 992, name: "Show Boat".to_string(), url: "Show Boat".to_string() },
-Musical { id: 993, name: "Show Girl".to_string(), url: "Show Girl (1929 musical)".to_string() },
-Musical { id: 994, name: "Showtune".to_string(), url: "Showtune (musical)".to_string() },
-Musical { id: 995, name: "Shrek The Musical".to_string(), url: "Shrek The Musical".to_string() },
-Musical { id: 996, name: "Side by Side by Sondheim".to_string(), url: "Side by Side by Sondheim".to_string() },
-Musical { id: 997, name: "Side Show".to_string(), url: "Side Show (musical)".to_string() },
-Musical { id: 998, name: "Silk Stockings".to_string(), url: "Silk Stockings".to_string() },
-Musical { id: 999, name: "Sing".to_string(), url: "Sing (1989 film)".to_string() },
-Musical { id: 1000, name: "Sing".to_string(), url: "Sing (2016 American film)".to_string() },
-Musical { id: 1001, name: "Sing As We Go".to_string(), url: "Sing As We Go".to_string() },
-Musical { id: 1002, name: "Singin' in the Rain".to_string(), url: "Singin&#39; in the Rain (musical)".to_string() },
-Musical { id: 1003, name: "Sister Act".to_string(), url: "Sister Act (musical)".to_string() },
-Musical { id: 1004, name: "Six".to_string(), url: "Six (musical)".to_string() },
-Musical { id: 1005, name: "Skittles Commercial: The Broadway Musical".to_string(), url: "Skittles Commercial: The Broadway Musical".to_string() },
-Musical { id: 1006, name: "Skyscraper".to_string(), url: "Skyscraper (musical)".to_string() },
-Musical { id: 1007, name: "Sleeping Beauty".to_string(), url: "Sleeping Beauty (1959 film)".to_string() },
-Musical { id: 1008, name: "Smike".to_string(), url: "Smike".to_string() },
-Musical { id: 1009, name: "Smile".to_string(), url: "Smile (musical)".to_string() },
-Musical { id: 1010, name: "Smoke on the Mountain".to_string(), url: "Smoke on the Mountain".to_string() },
-Musical { id: 1011, name: "Smokey Joe's Cafe".to_string(), url: "Smokey Joe&#39;s Cafe (revue)".to_string() },
-Musical { id: 1012, name: "Snoopy!".to_string(), url: "Snoopy! The Musical".to_string() },
-Musical { id: 1013, name: "Snoopy, Come Home".to_string(), url: "Snoopy, Come Home".to_string() },
-Musical { id: 1014, name: "Snow White and the Seven Dwarfs".to_string(), url: "Snow White and the Seven Dwarfs (musical)".to_string() },
-Musical { id: 1015, name: "Snow.Wolf.Lake".to_string(), url: "Snow.Wolf.Lake".to_string() },
-Musical { id: 1016, name: "Soho Cinders".to_string(), url: "Soho Cinders".to_string() },
-Musical { id: 1017, name: "Some Like It Hot".to_string(), url: "Some Like It Hot (musical)".to_string() },
-Musical { id: 1018, name: "Someone Like You".to_string(), url: "Someone like You (musical)".to_string() },
-Musical { id: 1019, name: "Something Rotten!".to_string(), url: "Something Rotten!".to_string() },
-Musical { id: 1020, name: "Sondheim on Sondheim".to_string(), url: "Sondheim on Sondheim".to_string() },
-Musical { id: 1021, name: "Song and Dance".to_string(), url: "Song and Dance".to_string() },
-Musical { id: 1022, name: "Song of Norway".to_string(), url: "Song of Norway".to_string() },
-Musical { id: 1023, name: "Songs for a New World".to_string(), url: "Songs for a New World".to_string() },
-Musical { id: 1024, name: "Sophisticated Ladies".to_string(), url: "Sophisticated Ladies".to_string() },
-Musical { id: 1025, name: "Soul Men".to_string(), url: "Soul Men".to_string() },
-Musical { id: 1026, name: "Soul Sister".to_string(), url: "Soul Sister (musical)".to_string() },
-Musical { id: 1027, name: "South Pacific".to_string(), url: "South Pacific (musical)".to_string() },
-Musical { id: 1028, name: "South Park: Bigger, Longer &amp; Uncut".to_string(), url: "South Park: Bigger, Longer &amp; Uncut".to_string() },
-Musical { id: 1029, name: "Spamalot".to_string(), url: "Spamalot".to_string() },
-Musical { id: 1030, name: "Spectacular!".to_string(), url: "Spectacular!".to_string() },
-Musical { id: 1031, name: "Spider-Man: Turn Off the Dark".to_string(), url: "Spider-Man: Turn Off the Dark".to_string() },
-Musical { id: 1032, name: "SpongeBob SquarePants".to_string(), url: "SpongeBob SquarePants (musical)".to_string() },
-Musical { id: 1033, name: "Spring Awakening".to_string(), url: "Spring Awakening (musical)".to_string() },
-Musical { id: 1034, name: "St. Louis Woman".to_string(), url: "St. Louis Woman".to_string() },
-Musical { id: 1035, name: "Starlight Express".to_string(), url: "Starlight Express".to_string() },
-Musical { id: 1036, name: "Starmania".to_string(), url: "Starmania (musical)".to_string() },
-Musical { id: 1037, name: "Starmites".to_string(), url: "Starmites".to_string() },
-Musical { id: 1038, name: "Starship".to_string(), url: "Starship (musical)".to_string() },
-Musical { id: 1039, name: "Starting Here, Starting Now".to_string(), url: "Starting Here, Starting Now".to_string() },
-Musical { id: 1040, name: "State Fair".to_string(), url: "State Fair (musical)".to_string() },
-Musical { id: 1041, name: "Steel Pier".to_string(), url: "Steel Pier (musical)".to_string() },
-Musical { id: 1042, name: "Step Up: All In".to_string(), url: "Step Up: All In".to_string() },
-Musical { id: 1043, name: "Stephen Ward".to_string(), url: "Stephen Ward the Musical".to_string() },
-Musical { id: 1044, name: "Stingray Sam".to_string(), url: "Stingray Sam".to_string() },
-Musical { id: 1045, name: "Stop the World – I Want to Get Off".to_string(), url: "Stop the World – I Want to Get Off".to_string() },
-Musical { id: 1046, name: "Street Scene".to_string(), url: "Street Scene (opera)".to_string() },
-Musical { id: 1047, name: "Strider".to_string(), url: "Strider (musical) (page does not exist)".to_string() },
-Musical { id: 1048, name: "Strike Up the Band".to_string(), url: "Strike Up the Band (musical)".to_string() },
-Musical { id: 1049, name: "Subways Are For Sleeping".to_string(), url: "Subways Are For Sleeping".to_string() },
-Musical { id: 1050, name: "Sugar".to_string(), url: "Sugar (musical)".to_string() },
-Musical { id: 1051, name: "Sugar Babies".to_string(), url: "Sugar Babies (musical)".to_string() },
-Musical { id: 1052, name: "Summer: The Donna Summer Musical".to_string(), url: "Summer: The Donna Summer Musical".to_string() },
-Musical { id: 1053, name: "Sunday in the Park with George".to_string(), url: "Sunday in the Park with George".to_string() },
-Musical { id: 1054, name: "Sunny Afternoon".to_string(), url: "Sunny Afternoon (musical)".to_string() },
-Musical { id: 1055, name: "Sunset Boulevard".to_string(), url: "Sunset Boulevard (musical)".to_string() },
-Musical { id: 1056, name: "Sweeney Todd: The Demon Barber of Fleet Street".to_string(), url: "Sweeney Todd: The Demon Barber of Fleet Street".to_string() },
-Musical { id: 1057, name: "Sweet Charity".to_string(), url: "Sweet Charity".to_string() },
-Musical { id: 1058, name: "Sweet Smell of Success".to_string(), url: "Sweet Smell of Success (musical)".to_string() },
-Musical { id: 1059, name: "Swing Time".to_string(), url: "Swing Time (film)".to_string() },
-Musical { id: 1060, name: "Swing!".to_string(), url: "Swing!".to_string() },
-Musical { id: 1061, name: "Swinging on a Star".to_string(), url: "Swinging on a Star (musical)".to_string() },
-Musical { id: 1062, name: "Taboo".to_string(), url: "Taboo (musical)".to_string() },
-Musical { id: 1063, name: "Take Flight!".to_string(), url: "Take Flight (musical)".to_string() },
-Musical { id: 1064, name: "Take Me Along".to_string(), url: "Take Me Along".to_string() },
-Musical { id: 1065, name: "Tältprojektet".to_string(), url: "Tältprojektet".to_string() },
-Musical { id: 1066, name: "Tangled".to_string(), url: "Tangled".to_string() },
-Musical { id: 1067, name: "Tarzan".to_string(), url: "Tarzan (musical)".to_string() },
-Musical { id: 1068, name: "Teacher's Pet".to_string(), url: "Teacher&#39;s Pet (2004 film)".to_string() },
-Musical { id: 1069, name: "Tenacious D in The Pick of Destiny".to_string(), url: "Tenacious D in The Pick of Destiny".to_string() },
-Musical { id: 1070, name: "Tenderloin".to_string(), url: "Tenderloin (musical)".to_string() },
-Musical { id: 1071, name: "That Midnight Kiss".to_string(), url: "That Midnight Kiss".to_string() },
-Musical { id: 1072, name: "The Things I Could Never Tell Steven".to_string(), url: "The Things I Could Never Tell Steven (page does not exist)".to_string() },
-Musical { id: 1073, name: "They're Playing Our Song".to_string(), url: "They&#39;re Playing Our Song".to_string() },
-Musical { id: 1074, name: "Thoroughly Modern Millie".to_string(), url: "Thoroughly Modern Millie (musical)".to_string() },
-Musical { id: 1075, name: "Thou Shalt Not".to_string(), url: "Thou Shalt Not (musical)".to_string() },
-Musical { id: 1076, name: "The Three Musketeers".to_string(), url: "The Three Musketeers (musical)".to_string() },
-Musical { id: 1077, name: "Three Wishes for Jamie".to_string(), url: "Three Wishes for Jamie".to_string() },
-Musical { id: 1078, name: "Tick, Tick... Boom!".to_string(), url: "Tick, Tick... Boom!".to_string() },
-Musical { id: 1079, name: "Tickets, Please!".to_string(), url: "Tickets, Please!".to_string() },
-Musical { id: 1080, name: "Tickle Me".to_string(), url: "Tickle Me (play)".to_string() },
-Musical { id: 1081, name: "Timbuktu!".to_string(), url: "Timbuktu!".to_string() },
-Musical { id: 1082, name: "Tina".to_string(), url: "Tina (musical)".to_string() },
-Musical { id: 1083, name: "Tintypes".to_string(), url: "Tintypes".to_string() },
-Musical { id: 1084, name: "Titanic".to_string(), url: "Titanic (musical)".to_string() },
-Musical { id: 1085, name: "title of show".to_string(), url: "Title of show".to_string() },
-Musical { id: 1086, name: "Tomorrow Morning".to_string(), url: "Tomorrow Morning (musical)".to_string() },
-Musical { id: 1087, name: "Tom Sawyer".to_string(), url: "Tom Sawyer (1973 film)".to_string() },
-Musical { id: 1088, name: "Tonight's the Night".to_string(), url: "Tonight&#39;s the Night (2003 musical)".to_string() },
-Musical { id: 1089, name: "Too Close to the Sun".to_string(), url: "Too Close to the Sun".to_string() },
-Musical { id: 1090, name: "Too Many Girls".to_string(), url: "Too Many Girls (musical)".to_string() },
-Musical { id: 1091, name: "Tootsie".to_string(), url: "Tootsie (musical)".to_string() },
-Musical { id: 1092, name: "Top Banana".to_string(), url: "Top Banana (musical)".to_string() },
-Musical { id: 1093, name: "Top Hat".to_string(), url: "Top Hat (musical)".to_string() },
-Musical { id: 1094, name: "Tovarich".to_string(), url: "Tovarich (musical)".to_string() },
-Musical { id: 1095, name: "Triassic Parq".to_string(), url: "Triassic Parq".to_string() },
-Musical { id: 1096, name: "Triumph of Love".to_string(), url: "Triumph of Love (musical)".to_string() },
-Musical { id: 1097, name: "Tuck Everlasting".to_string(), url: "Tuck Everlasting (musical)".to_string() },
-Musical { id: 1098, name: "Twang!!".to_string(), url: "Twang!!".to_string() },
-Musical { id: 1099, name: "Twisted".to_string(), url: "Twisted (musical)".to_string() },
-Musical { id: 1100, name: "Two by Two".to_string(), url: "Two by Two (musical)".to_string() },
-Musical { id: 1101, name: "Two Cities".to_string(), url: "Two Cities (musical)".to_string() },
-Musical { id: 1102, name: "Two Gentlemen of Verona".to_string(), url: "Two Gentlemen of Verona (musical)".to_string() },
-Musical { id: 1103, name: "Two on the Aisle".to_string(), url: "Two on the Aisle".to_string() },
-Musical { id: 1104, name: "Two's Company".to_string(), url: "Two&#39;s Company (musical)".to_string() },
-Musical { id: 1105, name: "Urban Cowboy".to_string(), url: "Urban Cowboy (musical)".to_string() },
-Musical { id: 1106, name: "Urinetown".to_string(), url: "Urinetown".to_string() },
-Musical { id: 1107, name: "Vanities, A New Musical".to_string(), url: "Vanities, A New Musical".to_string() },
-Musical { id: 1108, name: "Very Good, Eddie".to_string(), url: "Very Good, Eddie".to_string() },
-Musical { id: 1109, name: "Very Warm for May".to_string(), url: "Very Warm for May".to_string() },
-Musical { id: 1110, name: "Via Galactica".to_string(), url: "Via Galactica".to_string() },
-Musical { id: 1111, name: "Victor/Victoria".to_string(), url: "Victor/Victoria (musical)".to_string() },
-Musical { id: 1112, name: "Violet".to_string(), url: "Violet (musical)".to_string() },
-Musical { id: 1113, name: "Vivir Intentando".to_string(), url: "Vivir Intentando".to_string() },
-Musical { id: 1114, name: "Wait a Minim!".to_string(), url: "Wait a Minim!".to_string() },
-Musical { id: 1115, name: "Waitress".to_string(), url: "Waitress (musical)".to_string() },
-Musical { id: 1116, name: "Walk a Little Faster".to_string(), url: "Walk a Little Faster".to_string() },
-Musical { id: 1117, name: "Walking Happy".to_string(), url: "Walking Happy".to_string() },
-Musical { id: 1118, name: "Walmartopia".to_string(), url: "Walmartopia".to_string() },
-Musical { id: 1119, name: "War Paint".to_string(), url: "War Paint (musical)".to_string() },
-Musical { id: 1120, name: "We Are The Tigers".to_string(), url: "We Are The Tigers".to_string() },
-Musical { id: 1121, name: "We Will Rock You".to_string(), url: "We Will Rock You (musical)".to_string() },
-Musical { id: 1122, name: "Welcome to the Voice".to_string(), url: "Welcome to the Voice".to_string() },
-Musical { id: 1123, name: "Were the World Mine".to_string(), url: "Were the World Mine".to_string() },
-Musical { id: 1124, name: "West Side Story".to_string(), url: "West Side Story".to_string() },
-Musical { id: 1125, name: "What Makes Sammy Run?".to_string(), url: "What Makes Sammy Run?".to_string() },
-Musical { id: 1126, name: "What's New Pussycat?".to_string(), url: "What&#39;s New Pussycat? (musical)".to_string() },
-Musical { id: 1127, name: "The History of Tom Jones, a Foundling".to_string(), url: "The History of Tom Jones, a Foundling".to_string() },
-Musical { id: 1128, name: "Where's Charley?".to_string(), url: "Where&#39;s Charley?".to_string() },
-Musical { id: 1129, name: "Whistle Down the Wind".to_string(), url: "Whistle Down the Wind (1996 musical)".to_string() },
-Musical { id: 1130, name: "White Christmas".to_string(), url: "White Christmas (musical)".to_string() },
-Musical { id: 1131, name: "Whoopee!".to_string(), url: "Whoopee!".to_string() },
-Musical { id: 1132, name: "Wicked".to_string(), url: "Wicked (musical)".to_string() },
-Musical { id: 1133, name: "Wildcat".to_string(), url: "Wildcat (musical)".to_string() },
-Musical { id: 1134, name: "Willy Wonka &amp; the Chocolate Factory".to_string(), url: "Willy Wonka &amp; the Chocolate Factory".to_string() },
-Musical { id: 1135, name: "Willy Wonka".to_string(), url: "Roald Dahl&#39;s Willy Wonka (musical)".to_string() },
-Musical { id: 1136, name: "Willy Wonka Junior".to_string(), url: "Roald Dahl&#39;s Willy Wonka (musical)".to_string() },
-Musical { id: 1137, name: "Winnie the Pooh and a Day for Eeyore".to_string(), url: "Winnie the Pooh and a Day for Eeyore".to_string() },
-Musical { id: 1138, name: "Winnie the Pooh and the Blustery Day".to_string(), url: "Winnie the Pooh and the Blustery Day".to_string() },
-Musical { id: 1139, name: "Winnie the Pooh and the Honey Tree".to_string(), url: "Winnie the Pooh and the Honey Tree".to_string() },
-Musical { id: 1140, name: "Winnie the Pooh and Tigger Too".to_string(), url: "Winnie the Pooh and Tigger Too".to_string() },
-Musical { id: 1141, name: "Winnie the Pooh: The New Musical Adaptation".to_string(), url: "Winnie the Pooh: The New Musical Adaptation".to_string() },
-Musical { id: 1142, name: "Wish You Were Here".to_string(), url: "Wish You Were Here (musical)".to_string() },
-Musical { id: 1143, name: "Woman of the Year".to_string(), url: "Woman of the Year (musical)".to_string() },
-Musical { id: 1144, name: "Women on the Verge of a Nervous Breakdown".to_string(), url: "Women on the Verge of a Nervous Breakdown (musical)".to_string() },
-Musical { id: 1145, name: "Wonderful Town".to_string(), url: "Wonderful Town".to_string() },
-Musical { id: 1146, name: "Wonderland".to_string(), url: "Wonderland (musical)".to_string() },
-Musical { id: 1147, name: "The Woodsman".to_string(), url: "The Woodsman (play)".to_string() },
-Musical { id: 1148, name: "The Woodsman (Original Off-Broadway Solo Recording)".to_string(), url: "The Woodsman (play)".to_string() },
-Musical { id: 1149, name: "Words and Music".to_string(), url: "Words and Music (musical)".to_string() },
-Musical { id: 1150, name: "Working".to_string(), url: "Working (musical)".to_string() },
-Musical { id: 1151, name: "Wuthering Heights".to_string(), url: "Wuthering Heights (musical)".to_string() },
-Musical { id: 1152, name: "Xanadu".to_string(), url: "Xanadu (musical)".to_string() },
-Musical { id: 1153, name: "Yellow Submarine".to_string(), url: "Yellow Submarine (film)".to_string() },
-Musical { id: 1154, name: "Yentl".to_string(), url: "Yentl (film)".to_string() },
-Musical { id: 1155, name: "Yes Nurse! No Nurse!".to_string(), url: "Yes Nurse! No Nurse!".to_string() },
-Musical { id: 1156, name: "You Said It".to_string(), url: "You Said It".to_string() },
-Musical { id: 1157, name: "Young Frankenstein".to_string(), url: "Young Frankenstein (musical)".to_string() },
-Musical { id: 1158, name: "Your Own Thing".to_string(), url: "Your Own Thing".to_string() },
-Musical { id: 1159, name: "You're a Good Man, Charlie Brown".to_string(), url: "You&#39;re a Good Man, Charlie Brown".to_string() },
-Musical { id: 1160, name: "Zanna, Don't!".to_string(), url: "Zanna, Don&#39;t!".to_string() },
-Musical { id: 1161, name: "Zenda".to_string(), url: "Zenda (musical)".to_string() },
-Musical { id: 1162, name: "Ziegfeld Follies".to_string(), url: "Ziegfeld Follies (film)".to_string() },
-Musical { id: 1163, name: "Ziegfeld Follies of 1909".to_string(), url: "Ziegfeld Follies".to_string() },
-Musical { id: 1164, name: "Ziegfeld Follies of 1910".to_string(), url: "Ziegfeld Follies".to_string() },
-Musical { id: 1165, name: "Ziegfeld Follies of 1911".to_string(), url: "Ziegfeld Follies".to_string() },
-Musical { id: 1166, name: "Ziegfeld Follies of 1912".to_string(), url: "Ziegfeld Follies".to_string() },
-Musical { id: 1167, name: "Ziegfeld Follies of 1913".to_string(), url: "Ziegfeld Follies".to_string() },
-Musical { id: 1168, name: "Ziegfeld Follies of 1914".to_string(), url: "Ziegfeld Follies".to_string() },
-Musical { id: 1169, name: "Ziegfeld Follies of 1915".to_string(), url: "Ziegfeld Follies".to_string() },
-Musical { id: 1170, name: "Ziegfeld Follies of 1917".to_string(), url: "Ziegfeld Follies".to_string() },
-Musical { id: 1171, name: "Ziegfeld Follies of 1919".to_string(), url: "Ziegfeld Follies".to_string() },
-Musical { id: 1172, name: "Ziegfeld Follies of 1920".to_string(), url: "Ziegfeld Follies".to_string() },
-Musical { id: 1173, name: "Ziegfeld Follies of 1921".to_string(), url: "Ziegfeld Follies".to_string() },
-Musical { id: 1174, name: "Ziegfeld Follies of 1922".to_string(), url: "Ziegfeld Follies".to_string() },
-Musical { id: 1175, name: "Ziegfeld Follies of 1923".to_string(), url: "Ziegfeld Follies".to_string() },
-Musical { id: 1176, name: "Ziegfeld Follies of 1924".to_string(), url: "Ziegfeld Follies".to_string() },
-Musical { id: 1177, name: "Ziegfeld Follies of 1927".to_string(), url: "Ziegfeld Follies".to_string() },
-Musical { id: 1178, name: "Ziegfeld Follies of 1931".to_string(), url: "Ziegfeld Follies".to_string() },
-Musical { id: 1179, name: "Ziegfeld Follies of 1934".to_string(), url: "Ziegfeld Follies".to_string() },
-Musical { id: 1180, name: "Ziegfeld Follies of 1936".to_string(), url: "Ziegfeld Follies".to_string() },
-Musical { id: 1181, name: "Ziegfeld Follies of 1943".to_string(), url: "Ziegfeld Follies".to_string() },
-Musical { id: 1182, name: "Ziegfeld Follies of 1957".to_string(), url: "Ziegfeld Follies".to_string() },
-Musical { id: 1183, name: "Ziegfeld Follies of 1958".to_string(), url: "Ziegfeld Follies".to_string() },
-Musical { id: 1184, name: "Ziegfeld Girl".to_string(), url: "Ziegfeld Girl (film)".to_string() },
-Musical { id: 1185, name: "Zip Goes a Million".to_string(), url: "Zip Goes a Million".to_string() },
-Musical { id: 1186, name: "Zipp!".to_string(), url: "Zipp! (musical)".to_string() },
-Musical { id: 1187, name: "ZM".to_string(), url: "ZM (musical) (page does not exist)".to_string() },
-Musical { id: 1188, name: "Zombie Prom".to_string(), url: "Zombie Prom".to_string() },
-Musical { id: 1189, name: "Zombies".to_string(), url: "Zombies (2018 film)".to_string() },
-Musical { id: 1190, name: "Zombies from The Beyond".to_string(), url: "Zombies from The Beyond".to_string() },
-Musical { id: 1191, name: "Zorba".to_string(), url: "Zorba (musical)".to_string() },
-Musical { id: 1192, name: "Zorro".to_string(), url: "Zorro (musical)".to_string() },
-Musical { id: 1193, name: "Zuleika".to_string(), url: "Zuleika (musical)".to_string() },
-]
-});
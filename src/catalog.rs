@@ -0,0 +1,525 @@
+//! Building of the musicals catalog from scraped rows.
+//!
+//! Scraped rows are first cleaned: rows which are not musicals
+//! are dropped, and musicals sharing a name get a label telling them apart.
+//!
+//! `ListItem.musical_id` values live forever in shared urls, so an id must
+//! keep pointing at the same musical whenever the catalog is rebuilt.
+use crate::model::Musical;
+use crate::musicals::{Remap, RemapRecord};
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+
+/// Wikipedia disambiguations which tell that a page is about a person.
+const PERSON_DISAMBIGUATIONS: [&str; 9] = [
+    "author",
+    "composer",
+    "lyricist",
+    "musician",
+    "novelist",
+    "playwright",
+    "singer",
+    "songwriter",
+    "writer",
+];
+
+/// A musical as scraped from Wikipedia, before it gets an id.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Row {
+    pub name: String,
+    pub url: String,
+    pub year: Option<u16>,
+    pub composer: Option<String>,
+    pub lyricist: Option<String>,
+    pub book_writer: Option<String>,
+}
+
+impl Row {
+    pub fn new(name: &str, url: &str) -> Row {
+        Row {
+            name: name.to_string(),
+            url: url.to_string(),
+            ..Default::default()
+        }
+    }
+}
+
+impl From<&Musical> for Row {
+    fn from(musical: &Musical) -> Row {
+        Row {
+            name: musical.name.clone(),
+            url: musical.url.clone(),
+            year: musical.year,
+            composer: musical.composer.clone(),
+            lyricist: musical.lyricist.clone(),
+            book_writer: musical.book_writer.clone(),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum DropReason {
+    /// Listed in the exclusion table, with the reason given there.
+    Excluded(String),
+    /// The page is about a person (e.g. a composer linked from the row).
+    Person,
+    /// Same name and page as an earlier row.
+    Duplicate,
+    Empty,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Dropped {
+    pub name: String,
+    pub url: String,
+    pub reason: DropReason,
+}
+
+impl fmt::Display for DropReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DropReason::Excluded(reason) => write!(f, "excluded: {}", reason),
+            DropReason::Person => write!(f, "person"),
+            DropReason::Duplicate => write!(f, "duplicate"),
+            DropReason::Empty => write!(f, "empty"),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum IdConflict {
+    /// `id` used to be a musical and now is another one.
+    Reassigned { id: u64, was: String, now: String },
+    /// `id` disappeared from the catalog without being listed in the remap table.
+    Dropped { id: u64, was: String },
+    /// `id` is given to several musicals.
+    Duplicate { id: u64 },
+    /// `id` is listed in the remap table but is still in use.
+    Remapped { id: u64, now: String },
+}
+
+impl fmt::Display for IdConflict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            IdConflict::Reassigned { id, was, now } => {
+                write!(f, "id {} was {:?} and is now {:?}", id, was, now)
+            }
+            IdConflict::Dropped { id, was } => {
+                write!(
+                    f,
+                    "id {} ({:?}) was dropped but is not in the remap table",
+                    id, was
+                )
+            }
+            IdConflict::Duplicate { id } => write!(f, "id {} is used several times", id),
+            IdConflict::Remapped { id, now } => {
+                write!(
+                    f,
+                    "id {} is in the remap table but is used by {:?}",
+                    id, now
+                )
+            }
+        }
+    }
+}
+
+/// Decode the html entities the scraped rows may contain.
+pub fn unescape_html(text: &str) -> String {
+    text.replace("&#39;", "'")
+        .replace("&quot;", "\"")
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&amp;", "&")
+}
+
+/// Clean up a scraped row: decode html entities, trim spaces, and name rows
+/// without a link text after their page.
+pub fn normalize(name: &str, url: &str) -> (String, String) {
+    let name = unescape_html(name.trim());
+    // links to missing pages are titled after the page they would create
+    let url = unescape_html(url.trim().trim_end_matches(" (page does not exist)"));
+    if name.is_empty() {
+        let name = match disambiguation(&url) {
+            Some(disambiguation) => url[..url.len() - disambiguation.len() - 3].to_string(),
+            None => url.clone(),
+        };
+        (name, url)
+    } else {
+        (name, url)
+    }
+}
+
+/// Disambiguation of a Wikipedia page title: "1958 film" for "Aladdin (1958 film)".
+fn disambiguation(url: &str) -> Option<&str> {
+    url.strip_suffix(')')
+        .and_then(|url| url.rfind(" (").map(|start| &url[start + 2..]))
+}
+
+/// Read the exclusion table: the urls of scraped rows which are not musicals,
+/// with the reason why.
+pub fn parse_excluded(excluded_csv: &str) -> Result<HashMap<String, String>, csv::Error> {
+    csv::ReaderBuilder::new()
+        .delimiter(b'\t')
+        .from_reader(excluded_csv.as_bytes())
+        .deserialize::<(String, String)>()
+        .collect()
+}
+
+/// Split scraped rows between the musicals and the dropped rows.
+pub fn clean(rows: Vec<Row>, excluded: &HashMap<String, String>) -> (Vec<Row>, Vec<Dropped>) {
+    let mut kept = vec![];
+    let mut dropped = vec![];
+    let mut seen = HashSet::new();
+    for row in rows {
+        let (name, url) = normalize(&row.name, &row.url);
+        let reason = if url.is_empty() {
+            Some(DropReason::Empty)
+        } else if let Some(reason) = excluded.get(&url) {
+            Some(DropReason::Excluded(reason.clone()))
+        } else if disambiguation(&url).is_some_and(|d| PERSON_DISAMBIGUATIONS.contains(&d)) {
+            Some(DropReason::Person)
+        } else if !seen.insert((name.clone(), url.clone())) {
+            Some(DropReason::Duplicate)
+        } else {
+            None
+        };
+        match reason {
+            Some(reason) => dropped.push(Dropped { name, url, reason }),
+            None => kept.push(Row { name, url, ..row }),
+        }
+    }
+    (kept, dropped)
+}
+
+/// Label the musicals whose name is used by several musicals with the
+/// disambiguation of their Wikipedia page, or the page title itself.
+pub fn label_duplicates(musicals: &mut [Musical]) {
+    let mut counts: HashMap<String, usize> = HashMap::new();
+    for musical in musicals.iter() {
+        *counts.entry(musical.name.clone()).or_default() += 1;
+    }
+    for musical in musicals.iter_mut() {
+        musical.label = if counts[&musical.name] > 1 {
+            match disambiguation(&musical.url) {
+                Some(label) => Some(label.to_string()),
+                None if musical.url != musical.name => Some(musical.url.clone()),
+                None => None,
+            }
+        } else {
+            None
+        };
+    }
+}
+
+/// Retire the ids of `previous` musicals missing from `next`, so that they
+/// are never given to another musical.
+pub fn retire_dropped(previous: &[Musical], next: &[Musical], remap: &mut Remap) {
+    let ids: HashSet<u64> = next.iter().map(|m| m.id).collect();
+    for musical in previous {
+        if !ids.contains(&musical.id) {
+            remap.entry(musical.id).or_insert(None);
+        }
+    }
+}
+
+fn tsv_writer(has_headers: bool) -> csv::Writer<Vec<u8>> {
+    csv::WriterBuilder::new()
+        .delimiter(b'\t')
+        .has_headers(has_headers)
+        .from_writer(vec![])
+}
+
+fn into_string(writer: csv::Writer<Vec<u8>>) -> Result<String, Box<dyn std::error::Error>> {
+    Ok(String::from_utf8(writer.into_inner()?)?)
+}
+
+/// Write a catalog in the `musicals.csv` format.
+pub fn write_musicals(musicals: &[Musical]) -> Result<String, Box<dyn std::error::Error>> {
+    let mut writer = tsv_writer(false);
+    for musical in musicals {
+        writer.serialize(musical)?;
+    }
+    into_string(writer)
+}
+
+/// Write a remap table in the `musical_remap.csv` format, sorted by id.
+pub fn write_remap(remap: &Remap) -> Result<String, Box<dyn std::error::Error>> {
+    let mut writer = tsv_writer(true);
+    let mut records: Vec<RemapRecord> = remap
+        .iter()
+        .map(|(old_id, new_id)| RemapRecord {
+            old_id: *old_id,
+            new_id: *new_id,
+        })
+        .collect();
+    records.sort_by_key(|record| record.old_id);
+    for record in records {
+        writer.serialize(record)?;
+    }
+    into_string(writer)
+}
+
+/// Write the report of the dropped rows.
+pub fn write_dropped(dropped: &[Dropped]) -> Result<String, Box<dyn std::error::Error>> {
+    let mut writer = tsv_writer(false);
+    writer.write_record(["name", "url", "reason"])?;
+    for row in dropped {
+        writer.write_record([&row.name, &row.url, &row.reason.to_string()])?;
+    }
+    into_string(writer)
+}
+
+fn describe(musical: &Musical) -> String {
+    format!("{} ({})", musical.name, musical.url)
+}
+
+/// Give ids to scraped rows: rows already in `previous` keep
+/// their id, new ones get ids which were never used before.
+pub fn assign_ids(previous: &[Musical], remap: &Remap, rows: Vec<Row>) -> Vec<Musical> {
+    let ids: HashMap<(&str, &str), u64> = previous.iter().map(|m| (m.key(), m.id)).collect();
+    let mut next_id = previous
+        .iter()
+        .map(|m| m.id)
+        .chain(remap.keys().copied())
+        .chain(remap.values().flatten().copied())
+        .max()
+        .unwrap_or(0)
+        + 1;
+    rows.into_iter()
+        .map(|row| {
+            let id = match ids.get(&(row.url.as_str(), row.name.as_str())) {
+                Some(id) => *id,
+                None => {
+                    next_id += 1;
+                    next_id - 1
+                }
+            };
+            Musical {
+                id,
+                name: row.name,
+                url: row.url,
+                label: None,
+                year: row.year,
+                composer: row.composer,
+                lyricist: row.lyricist,
+                book_writer: row.book_writer,
+            }
+        })
+        .collect()
+}
+
+/// Check that going from the `previous` catalog to the `next` one keeps
+/// every existing id meaning the same musical.
+pub fn check_ids(
+    previous: &[Musical],
+    next: &[Musical],
+    remap: &Remap,
+) -> Result<(), Vec<IdConflict>> {
+    let mut conflicts = vec![];
+    let next_by_id: HashMap<u64, &Musical> = next.iter().map(|m| (m.id, m)).collect();
+    let mut seen = HashSet::new();
+    for musical in next {
+        if !seen.insert(musical.id) {
+            conflicts.push(IdConflict::Duplicate { id: musical.id });
+        }
+        if remap.contains_key(&musical.id) {
+            conflicts.push(IdConflict::Remapped {
+                id: musical.id,
+                now: describe(musical),
+            });
+        }
+    }
+    for musical in previous {
+        match next_by_id.get(&musical.id) {
+            Some(now) if now.key() != musical.key() => conflicts.push(IdConflict::Reassigned {
+                id: musical.id,
+                was: describe(musical),
+                now: describe(now),
+            }),
+            Some(_) => (),
+            None if remap.contains_key(&musical.id) => (),
+            None => conflicts.push(IdConflict::Dropped {
+                id: musical.id,
+                was: describe(musical),
+            }),
+        }
+    }
+    if conflicts.is_empty() {
+        Ok(())
+    } else {
+        Err(conflicts)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::musicals::{MUSICALS, REMAP};
+
+    fn rows(musicals: &[Musical]) -> Vec<Row> {
+        musicals.iter().map(Row::from).collect()
+    }
+
+    fn musical(id: u64, name: &str) -> Musical {
+        Musical {
+            id,
+            name: name.to_string(),
+            url: format!("{} (musical)", name),
+            ..Default::default()
+        }
+    }
+
+    fn row(name: &str, url: &str) -> Row {
+        Row::new(name, url)
+    }
+
+    #[test]
+    fn drops_rows_which_are_not_musicals() {
+        let excluded = HashMap::from([("Dodie Smith".to_string(), "author".to_string())]);
+        let (kept, dropped) = clean(
+            vec![
+                row("101 Dalmatians", "101 Dalmatians (2022 musical)"),
+                row("Dodie Smith", "Dodie Smith"),
+                row("Stephen Sondheim", "Stephen Sondheim (composer)"),
+                row("Les Misérables", "Les Misérables (musical)"),
+                row("Les Misérables", "Les Misérables (musical)"),
+                row("Ain't Too Proud", "Ain&#39;t Too Proud (musical)"),
+                row(" Bad Cinderella", "Cinderella (Lloyd Webber musical)"),
+                row("", "Daddy Long Legs (musical)"),
+                row("35mm", "35mm: A Musical Exhibition (page does not exist)"),
+                row("Nothing", ""),
+            ],
+            &excluded,
+        );
+        assert_eq!(
+            kept,
+            vec![
+                row("101 Dalmatians", "101 Dalmatians (2022 musical)"),
+                row("Les Misérables", "Les Misérables (musical)"),
+                row("Ain't Too Proud", "Ain't Too Proud (musical)"),
+                row("Bad Cinderella", "Cinderella (Lloyd Webber musical)"),
+                row("Daddy Long Legs", "Daddy Long Legs (musical)"),
+                row("35mm", "35mm: A Musical Exhibition"),
+            ]
+        );
+        let reasons: Vec<_> = dropped.iter().map(|d| d.reason.to_string()).collect();
+        assert_eq!(
+            reasons,
+            vec!["excluded: author", "person", "duplicate", "empty"]
+        );
+    }
+
+    #[test]
+    fn labels_musicals_sharing_a_name() {
+        let mut musicals = assign_ids(
+            &[],
+            &Remap::new(),
+            vec![
+                row("42nd Street", "42nd Street (film)"),
+                row("42nd Street", "42nd Street (musical)"),
+                row("Cyrano", "Cyrano: The Musical"),
+                row("Cyrano", "Cyrano"),
+                row("Evita", "Evita (musical)"),
+            ],
+        );
+        label_duplicates(&mut musicals);
+        let names: Vec<_> = musicals.iter().map(|m| m.display_name()).collect();
+        assert_eq!(
+            names,
+            vec![
+                "42nd Street (film)",
+                "42nd Street (musical)",
+                "Cyrano (Cyrano: The Musical)",
+                "Cyrano",
+                "Evita"
+            ]
+        );
+    }
+
+    #[test]
+    fn musicals_in_the_catalog_have_distinct_names() {
+        let mut names = HashSet::new();
+        for musical in MUSICALS.iter() {
+            assert!(
+                names.insert(musical.display_name()),
+                "{} can't be told apart",
+                musical.display_name()
+            );
+        }
+    }
+
+    #[test]
+    fn catalog_files_round_trip() {
+        use crate::musicals::{parse_musicals, parse_remap};
+        assert_eq!(
+            write_musicals(&MUSICALS).unwrap(),
+            include_str!("musicals.csv")
+        );
+        assert_eq!(
+            write_remap(&REMAP).unwrap(),
+            include_str!("musical_remap.csv")
+        );
+        assert_eq!(
+            parse_musicals(&write_musicals(&MUSICALS).unwrap()),
+            *MUSICALS
+        );
+        assert_eq!(parse_remap(&write_remap(&REMAP).unwrap()), *REMAP);
+    }
+
+    #[test]
+    fn rebuilding_the_catalog_keeps_its_ids() {
+        let (rows, dropped) = clean(rows(&MUSICALS), &HashMap::new());
+        assert_eq!(dropped, vec![]);
+        let mut next = assign_ids(&MUSICALS, &REMAP, rows);
+        label_duplicates(&mut next);
+        assert_eq!(next, *MUSICALS);
+        assert_eq!(check_ids(&MUSICALS, &next, &REMAP), Ok(()));
+    }
+
+    #[test]
+    fn new_rows_get_fresh_ids_wherever_they_are() {
+        let previous = vec![musical(1, "Cats"), musical(2, "Evita"), musical(3, "Hair")];
+        let remap = Remap::from([(4, None)]);
+        let mut scraped = rows(&previous);
+        scraped.insert(0, row("Annie", "Annie (musical)"));
+        scraped.reverse();
+        let next = assign_ids(&previous, &remap, scraped);
+        assert_eq!(
+            next,
+            vec![
+                musical(3, "Hair"),
+                musical(2, "Evita"),
+                musical(1, "Cats"),
+                musical(5, "Annie")
+            ]
+        );
+        assert_eq!(check_ids(&previous, &next, &remap), Ok(()));
+    }
+
+    #[test]
+    fn reassigned_and_dropped_ids_are_reported() {
+        let previous = vec![musical(1, "Cats"), musical(2, "Evita"), musical(3, "Hair")];
+        let next = vec![musical(1, "Annie"), musical(2, "Evita"), musical(2, "Cats")];
+        assert_eq!(
+            check_ids(&previous, &next, &Remap::new()),
+            Err(vec![
+                IdConflict::Duplicate { id: 2 },
+                IdConflict::Reassigned {
+                    id: 1,
+                    was: "Cats (Cats (musical))".to_string(),
+                    now: "Annie (Annie (musical))".to_string(),
+                },
+                IdConflict::Reassigned {
+                    id: 2,
+                    was: "Evita (Evita (musical))".to_string(),
+                    now: "Cats (Cats (musical))".to_string(),
+                },
+                IdConflict::Dropped {
+                    id: 3,
+                    was: "Hair (Hair (musical))".to_string(),
+                },
+            ])
+        );
+        assert!(check_ids(&previous, &previous[..2], &Remap::from([(3, None)])).is_ok());
+    }
+}
@@ -0,0 +1,470 @@
+//! Encoding of a [`MusicaList`] into the `content` string carried by sharing urls
+//! and stored in LocalStorage: msgpack, optionally deflated, then url-safe
+//! base64 without padding. A prefix tells which of these was used:
+//!
+//! - `m.`: msgpack only
+//! - `z.`: deflated msgpack
+//!
+//! Content without a prefix was written before those existed: standard
+//! base64 of the msgpack bytes.
+//!
+//! Lists written by older versions are upgraded on decode, see [`migration`].
+//! This module does not depend on the browser so that sharing urls can be
+//! produced and read by tooling too.
+use crate::migration;
+use crate::model::MusicaList;
+use crate::musicals::remap_list;
+use base64::alphabet;
+use base64::engine::{general_purpose, DecodePaddingMode, GeneralPurpose, GeneralPurposeConfig};
+use base64::Engine as _;
+use miniz_oxide::{deflate::compress_to_vec, inflate};
+use std::fmt;
+
+pub const CURRENT_VERSION: u8 = 6;
+
+const MSGPACK_PREFIX: &str = "m.";
+const DEFLATE_PREFIX: &str = "z.";
+
+#[derive(Debug)]
+pub enum EncodeError {
+    Msgpack(rmp_serde::encode::Error),
+}
+
+#[derive(Debug)]
+pub enum DecodeError {
+    Base64(base64::DecodeError),
+    Deflate(inflate::DecompressError),
+    Msgpack(rmp_serde::decode::Error),
+    UnsupportedVersion(u8),
+}
+
+impl fmt::Display for EncodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EncodeError::Msgpack(err) => write!(f, "could not serialize list: {}", err),
+        }
+    }
+}
+
+impl fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DecodeError::Base64(err) => write!(f, "content is not valid base64: {}", err),
+            DecodeError::Deflate(err) => write!(f, "content can't be decompressed: {}", err),
+            DecodeError::Msgpack(err) => write!(f, "content is not a valid list: {}", err),
+            DecodeError::UnsupportedVersion(version) => {
+                write!(f, "list version {} is not supported", version)
+            }
+        }
+    }
+}
+
+impl std::error::Error for EncodeError {}
+impl std::error::Error for DecodeError {}
+
+impl From<rmp_serde::encode::Error> for EncodeError {
+    fn from(err: rmp_serde::encode::Error) -> Self {
+        EncodeError::Msgpack(err)
+    }
+}
+
+impl From<base64::DecodeError> for DecodeError {
+    fn from(err: base64::DecodeError) -> Self {
+        DecodeError::Base64(err)
+    }
+}
+
+impl From<inflate::DecompressError> for DecodeError {
+    fn from(err: inflate::DecompressError) -> Self {
+        DecodeError::Deflate(err)
+    }
+}
+
+impl From<rmp_serde::decode::Error> for DecodeError {
+    fn from(err: rmp_serde::decode::Error) -> Self {
+        DecodeError::Msgpack(err)
+    }
+}
+
+/// Leading `version` field of a serialized list, read without assuming
+/// anything about the fields that follow it.
+struct VersionHeader(u8);
+
+impl<'de> serde::Deserialize<'de> for VersionHeader {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct Visitor;
+        impl<'de> serde::de::Visitor<'de> for Visitor {
+            type Value = VersionHeader;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("a list starting with its version")
+            }
+
+            fn visit_seq<A: serde::de::SeqAccess<'de>>(
+                self,
+                mut seq: A,
+            ) -> Result<Self::Value, A::Error> {
+                let version = seq
+                    .next_element::<u8>()?
+                    .ok_or_else(|| serde::de::Error::invalid_length(0, &self))?;
+                while seq.next_element::<serde::de::IgnoredAny>()?.is_some() {}
+                Ok(VersionHeader(version))
+            }
+        }
+        deserializer.deserialize_seq(Visitor)
+    }
+}
+
+/// Encode `list`, deflating it only when that makes the content shorter.
+/// The content is always stamped with the current version, which is the
+/// layout it is serialized in, whatever the version of `list` says.
+pub fn encode(list: &MusicaList) -> Result<String, EncodeError> {
+    let bytes = rmp_serde::to_vec(&MusicaList {
+        version: CURRENT_VERSION,
+        ..list.clone()
+    })?;
+    let deflated = compress_to_vec(&bytes, 10);
+    Ok(if deflated.len() < bytes.len() {
+        format!(
+            "{}{}",
+            DEFLATE_PREFIX,
+            general_purpose::URL_SAFE_NO_PAD.encode(deflated)
+        )
+    } else {
+        format!(
+            "{}{}",
+            MSGPACK_PREFIX,
+            general_purpose::URL_SAFE_NO_PAD.encode(bytes)
+        )
+    })
+}
+
+/// Legacy content is standard base64, whose `+` becomes a space when the
+/// url was not percent-encoded.
+fn legacy_base64(content: &str) -> String {
+    content.replace(' ', "+")
+}
+
+fn msgpack_bytes(content: &str) -> Result<Vec<u8>, DecodeError> {
+    Ok(
+        if let Some(content) = content.strip_prefix(DEFLATE_PREFIX) {
+            inflate::decompress_to_vec(&general_purpose::URL_SAFE_NO_PAD.decode(content)?)?
+        } else if let Some(content) = content.strip_prefix(MSGPACK_PREFIX) {
+            general_purpose::URL_SAFE_NO_PAD.decode(content)?
+        } else {
+            general_purpose::STANDARD.decode(legacy_base64(content))?
+        },
+    )
+}
+
+pub fn decode(content: &str) -> Result<MusicaList, DecodeError> {
+    let bytes = msgpack_bytes(content)?;
+    let VersionHeader(version) = rmp_serde::from_slice(&bytes)?;
+    let mut list = migration::migrate(version, &bytes)?;
+    remap_list(&mut list);
+    list.repair_ids();
+    Ok(list)
+}
+
+/// Recover what can still be read from `content` when [`decode`] fails,
+/// typically because the url was cut when it was pasted somewhere.
+pub fn salvage(content: &str) -> Option<MusicaList> {
+    fn valid_prefix(content: &str, extra: [char; 2]) -> String {
+        let content: String = content
+            .chars()
+            .take_while(|c| c.is_ascii_alphanumeric() || extra.contains(c))
+            .collect();
+        // drop the incomplete trailing quantum, if any
+        let len = content.len() / 4 * 4 + [0, 0, 2, 3][content.len() % 4];
+        content[..len].to_string()
+    }
+    // the last character of a cut url may be the first half of a byte,
+    // leaving trailing bits which the strict engines reject
+    let lenient = GeneralPurpose::new(
+        &alphabet::URL_SAFE,
+        GeneralPurposeConfig::new()
+            .with_decode_padding_mode(DecodePaddingMode::Indifferent)
+            .with_decode_allow_trailing_bits(true),
+    );
+    let url_safe = |content| lenient.decode(valid_prefix(content, ['-', '_'])).ok();
+    let bytes = if let Some(content) = content.strip_prefix(DEFLATE_PREFIX) {
+        match inflate::decompress_to_vec(&url_safe(content)?) {
+            Ok(bytes) => bytes,
+            Err(err) => err.output,
+        }
+    } else if let Some(content) = content.strip_prefix(MSGPACK_PREFIX) {
+        url_safe(content)?
+    } else {
+        let content = valid_prefix(&legacy_base64(content), ['+', '/']);
+        general_purpose::STANDARD
+            .decode(&content[..content.len() / 4 * 4])
+            .ok()?
+    };
+    let mut list = migration::salvage(&bytes)?;
+    remap_list(&mut list);
+    list.repair_ids();
+    Some(list)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::{CustomMusical, ListItem, RatingScale, Status, Viewing};
+
+    fn sample() -> MusicaList {
+        MusicaList {
+            version: CURRENT_VERSION,
+            author: "Yazgoo".to_string(),
+            rating_scale: RatingScale::Ten,
+            tags: vec![],
+            items: vec![
+                ListItem {
+                    id: 1,
+                    musical_id: 565,
+                    status: Status::Seen,
+                    rating: 10,
+                    review: None,
+                    viewings: vec![],
+                    tags: vec![],
+                    custom: None,
+                },
+                ListItem {
+                    id: 2,
+                    musical_id: 933,
+                    status: Status::WantToSee,
+                    rating: 0,
+                    review: None,
+                    viewings: vec![],
+                    tags: vec![],
+                    custom: None,
+                },
+            ],
+        }
+    }
+
+    #[test]
+    fn round_trip() {
+        let list = sample();
+        assert_eq!(decode(&encode(&list).unwrap()).unwrap(), list);
+    }
+
+    #[test]
+    fn round_trip_viewings() {
+        let mut list = sample();
+        list.items[0].review = Some("Still great".to_string());
+        list.items[0].viewings = vec![
+            Viewing {
+                date: Some("2023-05-14".to_string()),
+                theatre: Some("Sondheim Theatre".to_string()),
+                city: Some("London".to_string()),
+                cast: None,
+                rating: Some(9),
+            },
+            Viewing::default(),
+        ];
+        assert_eq!(decode(&encode(&list).unwrap()).unwrap(), list);
+    }
+
+    #[test]
+    fn round_trip_tags_and_custom_musicals() {
+        let mut list = sample();
+        list.add_tag(1, "Sondheim");
+        list.add_tag(2, "saw with Ana");
+        list.add_tag(1, "saw with Ana");
+        list.items[1].custom = Some(CustomMusical {
+            title: "A fringe show".to_string(),
+            url: Some("https://example.com/fringe".to_string()),
+        });
+        assert_eq!(decode(&encode(&list).unwrap()).unwrap(), list);
+    }
+
+    #[test]
+    fn encodes_the_current_version() {
+        let list = MusicaList {
+            version: 1,
+            ..sample()
+        };
+        assert_eq!(decode(&encode(&list).unwrap()).unwrap(), sample());
+    }
+
+    #[test]
+    fn round_trip_empty() {
+        let list = MusicaList {
+            version: CURRENT_VERSION,
+            author: "".to_string(),
+            rating_scale: RatingScale::default(),
+            tags: vec![],
+            items: vec![],
+        };
+        assert_eq!(decode(&encode(&list).unwrap()).unwrap(), list);
+    }
+
+    #[test]
+    fn encodes_url_safe() {
+        let content = encode(&sample()).unwrap();
+        assert!(content.starts_with(MSGPACK_PREFIX) || content.starts_with(DEFLATE_PREFIX));
+        assert!(content
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || "-_.".contains(c)));
+    }
+
+    #[test]
+    fn deflates_long_lists() {
+        let list = MusicaList {
+            items: (1..=100)
+                .map(|id| ListItem {
+                    id,
+                    musical_id: 1000 + id,
+                    status: Status::ALL[id as usize % 4],
+                    rating: (id % 11) as u8,
+                    review: None,
+                    viewings: vec![],
+                    tags: vec![],
+                    custom: None,
+                })
+                .collect(),
+            ..sample()
+        };
+        let content = encode(&list).unwrap();
+        assert!(content.starts_with(DEFLATE_PREFIX));
+        assert!(
+            content.len()
+                < general_purpose::STANDARD
+                    .encode(rmp_serde::to_vec(&list).unwrap())
+                    .len()
+        );
+        assert_eq!(decode(&content).unwrap(), list);
+    }
+
+    #[test]
+    fn decodes_legacy_content() {
+        let list = sample();
+        let legacy = general_purpose::STANDARD.encode(rmp_serde::to_vec(&list).unwrap());
+        assert_eq!(decode(&legacy).unwrap(), list);
+    }
+
+    #[test]
+    fn decodes_legacy_content_with_unescaped_plus() {
+        // "+" in an unescaped url query is read as a space
+        let list = MusicaList {
+            author: ">>>".to_string(),
+            ..sample()
+        };
+        let legacy = general_purpose::STANDARD.encode(rmp_serde::to_vec(&list).unwrap());
+        assert!(legacy.contains('+'));
+        assert_eq!(decode(&legacy.replace('+', " ")).unwrap(), list);
+    }
+
+    #[test]
+    fn bad_base64() {
+        assert!(matches!(decode("not base64!"), Err(DecodeError::Base64(_))));
+        assert!(matches!(decode("m.a+b/"), Err(DecodeError::Base64(_))));
+    }
+
+    #[test]
+    fn bad_deflate() {
+        let content = format!(
+            "{}{}",
+            DEFLATE_PREFIX,
+            general_purpose::URL_SAFE_NO_PAD.encode([0xff; 4])
+        );
+        assert!(matches!(decode(&content), Err(DecodeError::Deflate(_))));
+    }
+
+    #[test]
+    fn bad_msgpack() {
+        let content = format!(
+            "{}{}",
+            MSGPACK_PREFIX,
+            general_purpose::URL_SAFE_NO_PAD.encode([0xc1, 0x00])
+        );
+        assert!(matches!(decode(&content), Err(DecodeError::Msgpack(_))));
+    }
+
+    #[test]
+    fn truncated_content() {
+        let bytes = rmp_serde::to_vec(&sample()).unwrap();
+        let truncated = format!(
+            "{}{}",
+            MSGPACK_PREFIX,
+            general_purpose::URL_SAFE_NO_PAD.encode(&bytes[..bytes.len() / 2])
+        );
+        assert!(matches!(decode(&truncated), Err(DecodeError::Msgpack(_))));
+    }
+
+    #[test]
+    fn repairs_colliding_ids() {
+        let mut list = sample();
+        list.items[1].id = 1;
+        let decoded = decode(&encode(&list).unwrap()).unwrap();
+        let ids: Vec<u64> = decoded.items.iter().map(|item| item.id).collect();
+        assert_eq!(ids, vec![1, 2]);
+        assert_eq!(decoded.items[1].musical_id, 933);
+    }
+
+    #[test]
+    fn salvages_truncated_content() {
+        let list = sample();
+        let content = encode(&list).unwrap();
+        let salvaged = salvage(&content[..content.len() - 3]).unwrap();
+        assert_eq!(salvaged.author, list.author);
+        assert_eq!(salvaged.items, list.items[..1]);
+        assert_eq!(salvage(&content).unwrap(), list);
+    }
+
+    #[test]
+    fn salvages_truncated_legacy_content() {
+        let list = sample();
+        let content = general_purpose::STANDARD.encode(rmp_serde::to_vec(&list).unwrap());
+        let salvaged = salvage(&content[..content.len() - 3]).unwrap();
+        assert_eq!(salvaged.items, list.items[..1]);
+    }
+
+    #[test]
+    fn salvages_truncated_deflated_content() {
+        let list = MusicaList {
+            items: (1..=100)
+                .map(|id| ListItem {
+                    id,
+                    musical_id: id,
+                    status: Status::WantToSee,
+                    rating: 0,
+                    review: None,
+                    viewings: vec![],
+                    tags: vec![],
+                    custom: None,
+                })
+                .collect(),
+            ..sample()
+        };
+        let content = encode(&list).unwrap();
+        assert!(decode(&content[..content.len() / 2]).is_err());
+        let salvaged = salvage(&content[..content.len() / 2]).unwrap();
+        assert!(!salvaged.items.is_empty());
+        assert_eq!(salvaged.items, list.items[..salvaged.items.len()]);
+    }
+
+    #[test]
+    fn salvages_nothing_from_garbage() {
+        assert_eq!(salvage("not base64!"), None);
+        assert_eq!(salvage(""), None);
+    }
+
+    #[test]
+    fn unsupported_version() {
+        let list = MusicaList {
+            version: CURRENT_VERSION + 1,
+            ..sample()
+        };
+        // as a later version would encode it
+        let content = format!(
+            "{}{}",
+            MSGPACK_PREFIX,
+            general_purpose::URL_SAFE_NO_PAD.encode(rmp_serde::to_vec(&list).unwrap())
+        );
+        assert!(matches!(
+            decode(&content),
+            Err(DecodeError::UnsupportedVersion(v)) if v == CURRENT_VERSION + 1
+        ));
+    }
+}
@@ -0,0 +1,316 @@
+//! Comparison of the lists of several users, lining up the items of the same
+//! musical to see where they agree, or to rank musicals across a group.
+//!
+//! Lists can have different rating scales: ratings are compared out of 100.
+use crate::model::{ListItem, MusicaList, Musical, RatingScale, Status};
+use crate::search::normalize;
+use std::collections::HashMap;
+
+/// What makes items of different lists the same musical: its catalog id, or
+/// the normalized title of a musical missing from the catalog.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum MusicalKey {
+    Catalog(u64),
+    Custom(String),
+}
+
+impl MusicalKey {
+    pub fn of(item: &ListItem) -> MusicalKey {
+        match &item.custom {
+            Some(custom) => MusicalKey::Custom(normalize(&custom.title)),
+            None => MusicalKey::Catalog(item.musical_id),
+        }
+    }
+}
+
+/// One musical and its item in each compared list, if any.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Row<'a> {
+    pub items: Vec<Option<&'a ListItem>>,
+    /// Rating out of 100 in each list which has seen the musical.
+    pub ratings: Vec<Option<u8>>,
+}
+
+impl Row<'_> {
+    pub fn display_name(&self, musicals: &[Musical]) -> String {
+        self.items
+            .iter()
+            .flatten()
+            .next()
+            .map(|item| item.display_name(musicals))
+            .unwrap_or_default()
+    }
+
+    pub fn seen_by(&self) -> usize {
+        self.ratings.iter().flatten().count()
+    }
+
+    /// Number of lists which want to see the musical or booked it.
+    pub fn wanted_by(&self) -> usize {
+        self.items
+            .iter()
+            .flatten()
+            .filter(|item| matches!(item.status, Status::WantToSee | Status::Booked))
+            .count()
+    }
+
+    /// Average rating out of 100 of the lists which have seen the musical.
+    pub fn average(&self) -> Option<u8> {
+        let ratings: Vec<u32> = self.ratings.iter().flatten().map(|r| *r as u32).collect();
+        let len = ratings.len() as u32;
+        (len > 0).then(|| ((ratings.iter().sum::<u32>() + len / 2) / len) as u8)
+    }
+
+    /// Difference between the highest and lowest ratings, out of 100, when
+    /// seen in at least two lists.
+    pub fn spread(&self) -> Option<u8> {
+        let ratings: Vec<u8> = self.ratings.iter().flatten().copied().collect();
+        let (min, max) = (ratings.iter().min()?, ratings.iter().max()?);
+        (ratings.len() > 1).then_some(max - min)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Comparison<'a> {
+    /// Musicals in at least one list, in the order they first appear.
+    pub rows: Vec<Row<'a>>,
+}
+
+/// Line up `lists` by musical. A musical listed twice in a list is compared
+/// by its first item.
+pub fn compare(lists: &[MusicaList]) -> Comparison<'_> {
+    let mut rows: Vec<Row> = vec![];
+    let mut row_of: HashMap<MusicalKey, usize> = HashMap::new();
+    for (l, list) in lists.iter().enumerate() {
+        for item in list.items.iter() {
+            let r = *row_of.entry(MusicalKey::of(item)).or_insert_with(|| {
+                rows.push(Row {
+                    items: vec![None; lists.len()],
+                    ratings: vec![None; lists.len()],
+                });
+                rows.len() - 1
+            });
+            if rows[r].items[l].is_none() {
+                rows[r].items[l] = Some(item);
+                rows[r].ratings[l] = (item.status == Status::Seen).then(|| {
+                    list.rating_scale
+                        .convert(item.aggregate_rating(), RatingScale::Hundred)
+                });
+            }
+        }
+    }
+    Comparison { rows }
+}
+
+impl<'a> Comparison<'a> {
+    /// Musicals seen in every list, most disputed first.
+    pub fn seen_by_all(&self) -> Vec<&Row<'a>> {
+        let mut rows: Vec<&Row> = self
+            .rows
+            .iter()
+            .filter(|row| row.seen_by() == row.ratings.len())
+            .collect();
+        rows.sort_by_key(|row| std::cmp::Reverse(row.spread()));
+        rows
+    }
+
+    /// Musicals seen in some lists but not all of them.
+    pub fn seen_by_some(&self) -> Vec<&Row<'a>> {
+        self.rows
+            .iter()
+            .filter(|row| row.seen_by() > 0 && row.seen_by() < row.ratings.len())
+            .collect()
+    }
+
+    /// Musicals seen in at least one list, best average rating first, then
+    /// most seen.
+    pub fn leaderboard(&self) -> Vec<&Row<'a>> {
+        let mut rows: Vec<&Row> = self.rows.iter().filter(|row| row.seen_by() > 0).collect();
+        rows.sort_by_key(|row| std::cmp::Reverse((row.average(), row.seen_by())));
+        rows
+    }
+
+    /// Musicals that lists want to see or booked and none has seen yet, most
+    /// wanted first.
+    pub fn most_wanted(&self) -> Vec<&Row<'a>> {
+        let mut rows: Vec<&Row> = self
+            .rows
+            .iter()
+            .filter(|row| row.wanted_by() > 0 && row.seen_by() == 0)
+            .collect();
+        rows.sort_by_key(|row| std::cmp::Reverse(row.wanted_by()));
+        rows
+    }
+
+    /// How close the ratings of the lists are, from 0 to 100: 100 less the
+    /// average difference of ratings of the same musical by two lists. None
+    /// when no musical was seen in two lists.
+    pub fn agreement(&self) -> Option<u8> {
+        let mut differences = vec![];
+        for row in self.rows.iter() {
+            let ratings: Vec<u8> = row.ratings.iter().flatten().copied().collect();
+            for (i, a) in ratings.iter().enumerate() {
+                for b in ratings[i + 1..].iter() {
+                    differences.push(a.abs_diff(*b) as u32);
+                }
+            }
+        }
+        if differences.is_empty() {
+            return None;
+        }
+        let len = differences.len() as u32;
+        let average = (differences.iter().sum::<u32>() + len / 2) / len;
+        Some(100 - average as u8)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::CustomMusical;
+
+    fn item(musical_id: u64, status: Status, rating: u8) -> ListItem {
+        ListItem {
+            id: musical_id,
+            musical_id,
+            status,
+            rating,
+            ..Default::default()
+        }
+    }
+
+    fn list(rating_scale: RatingScale, items: Vec<ListItem>) -> MusicaList {
+        MusicaList {
+            rating_scale,
+            items,
+            ..Default::default()
+        }
+    }
+
+    fn lists() -> Vec<MusicaList> {
+        vec![
+            list(
+                RatingScale::Ten,
+                vec![
+                    item(1, Status::Seen, 8),
+                    item(2, Status::Seen, 4),
+                    item(3, Status::Seen, 10),
+                    item(4, Status::WantToSee, 0),
+                ],
+            ),
+            list(
+                RatingScale::Hundred,
+                vec![
+                    item(2, Status::Seen, 90),
+                    item(1, Status::Seen, 80),
+                    item(4, Status::Seen, 50),
+                    item(5, Status::Booked, 0),
+                ],
+            ),
+        ]
+    }
+
+    fn musical_ids(rows: Vec<&Row>) -> Vec<u64> {
+        rows.iter()
+            .map(|row| row.items.iter().flatten().next().unwrap().musical_id)
+            .collect()
+    }
+
+    #[test]
+    fn lines_up_musicals_across_scales() {
+        let lists = lists();
+        let comparison = compare(&lists);
+        assert_eq!(comparison.rows.len(), 5);
+        assert_eq!(comparison.rows[0].ratings, vec![Some(80), Some(80)]);
+        assert_eq!(comparison.rows[1].ratings, vec![Some(40), Some(90)]);
+        assert_eq!(comparison.rows[1].spread(), Some(50));
+        assert_eq!(comparison.rows[4].items[0], None);
+    }
+
+    #[test]
+    fn splits_musicals_seen_by_all_or_some() {
+        let lists = lists();
+        let comparison = compare(&lists);
+        // the biggest disagreement first
+        assert_eq!(musical_ids(comparison.seen_by_all()), vec![2, 1]);
+        assert_eq!(musical_ids(comparison.seen_by_some()), vec![3, 4]);
+    }
+
+    #[test]
+    fn scores_agreement() {
+        let lists = lists();
+        // differences of 0 and 50
+        assert_eq!(compare(&lists).agreement(), Some(75));
+        assert_eq!(compare(&lists[..1]).agreement(), None);
+        let same = vec![lists[0].clone(), lists[0].clone()];
+        assert_eq!(compare(&same).agreement(), Some(100));
+    }
+
+    #[test]
+    fn ranks_musicals_across_the_group() {
+        let mut lists = lists();
+        lists.push(list(
+            RatingScale::Five,
+            vec![
+                item(4, Status::Booked, 0),
+                item(5, Status::WantToSee, 0),
+                item(3, Status::Seen, 4),
+            ],
+        ));
+        let comparison = compare(&lists);
+        // 3: 100 and 80, 1: 80 and 80, then 2: 40 and 90, and 4: 50
+        assert_eq!(musical_ids(comparison.leaderboard()), vec![3, 1, 2, 4]);
+        assert_eq!(comparison.rows[1].average(), Some(65));
+        assert_eq!(comparison.rows[4].average(), None);
+        // 4 is wanted twice too, but was seen once
+        assert_eq!(musical_ids(comparison.most_wanted()), vec![5]);
+        assert_eq!(comparison.rows[4].wanted_by(), 2);
+    }
+
+    #[test]
+    fn most_wanted_leaves_out_what_was_seen() {
+        let lists = vec![
+            list(
+                RatingScale::Ten,
+                vec![item(1, Status::Seen, 8), item(2, Status::WantToSee, 0)],
+            ),
+            list(
+                RatingScale::Ten,
+                vec![item(1, Status::WantToSee, 0), item(3, Status::Booked, 0)],
+            ),
+            list(
+                RatingScale::Ten,
+                vec![
+                    item(1, Status::Booked, 0),
+                    item(3, Status::WantToSee, 0),
+                    item(2, Status::Abandoned, 0),
+                ],
+            ),
+        ];
+        let comparison = compare(&lists);
+        // 1 is wanted most but was seen once, 2 was only abandoned
+        assert_eq!(musical_ids(comparison.most_wanted()), vec![3, 2]);
+    }
+
+    #[test]
+    fn lines_up_custom_musicals_by_title() {
+        let custom = |title: &str| ListItem {
+            custom: Some(CustomMusical {
+                title: title.to_string(),
+                url: None,
+            }),
+            ..item(0, Status::Seen, 6)
+        };
+        let lists = vec![
+            list(
+                RatingScale::Ten,
+                vec![custom("Fringe Show"), item(0, Status::Seen, 6)],
+            ),
+            list(RatingScale::Ten, vec![custom("fringe show!")]),
+        ];
+        let comparison = compare(&lists);
+        assert_eq!(comparison.rows.len(), 2);
+        assert_eq!(comparison.rows[0].seen_by(), 2);
+        assert_eq!(comparison.rows[0].display_name(&[]), "Fringe Show");
+    }
+}
@@ -0,0 +1,118 @@
+//! Undo/redo history of the edits of a list, kept by the app itself rather
+//! than by the browser so that undoing never leaves the page.
+use std::collections::VecDeque;
+
+/// Number of states which can be undone.
+pub const HISTORY_LIMIT: usize = 50;
+
+#[derive(Debug, serde::Deserialize, serde::Serialize, Clone, PartialEq)]
+pub struct History<T> {
+    past: VecDeque<T>,
+    present: T,
+    future: Vec<T>,
+}
+
+impl<T: Clone + PartialEq> History<T> {
+    pub fn new(present: T) -> History<T> {
+        History {
+            past: VecDeque::new(),
+            present,
+            future: vec![],
+        }
+    }
+
+    pub fn present(&self) -> &T {
+        &self.present
+    }
+
+    /// Make `next` the present state, forgetting what could be redone.
+    /// Returns false, recording nothing, when `next` is the present state.
+    pub fn push(&mut self, next: T) -> bool {
+        if next == self.present {
+            return false;
+        }
+        self.past
+            .push_back(std::mem::replace(&mut self.present, next));
+        if self.past.len() > HISTORY_LIMIT {
+            self.past.pop_front();
+        }
+        self.future.clear();
+        true
+    }
+
+    /// Go back to the previous state, if any, and return it.
+    pub fn undo(&mut self) -> Option<&T> {
+        let previous = self.past.pop_back()?;
+        self.future
+            .push(std::mem::replace(&mut self.present, previous));
+        Some(&self.present)
+    }
+
+    /// Go forward to the state undone last, if any, and return it.
+    pub fn redo(&mut self) -> Option<&T> {
+        let next = self.future.pop()?;
+        self.past
+            .push_back(std::mem::replace(&mut self.present, next));
+        Some(&self.present)
+    }
+
+    pub fn can_undo(&self) -> bool {
+        !self.past.is_empty()
+    }
+
+    pub fn can_redo(&self) -> bool {
+        !self.future.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn undoes_and_redoes() {
+        let mut history = History::new(0);
+        assert!(history.push(1));
+        assert!(history.push(2));
+        assert_eq!(history.undo(), Some(&1));
+        assert_eq!(history.undo(), Some(&0));
+        assert_eq!(history.undo(), None);
+        assert_eq!(history.present(), &0);
+        assert_eq!(history.redo(), Some(&1));
+        assert_eq!(history.redo(), Some(&2));
+        assert_eq!(history.redo(), None);
+        assert_eq!(history.present(), &2);
+    }
+
+    #[test]
+    fn push_forgets_the_redo_states() {
+        let mut history = History::new(0);
+        history.push(1);
+        history.undo();
+        assert!(history.can_redo());
+        history.push(2);
+        assert!(!history.can_redo());
+        assert_eq!(history.undo(), Some(&0));
+    }
+
+    #[test]
+    fn ignores_unchanged_states() {
+        let mut history = History::new(0);
+        assert!(!history.push(0));
+        assert!(!history.can_undo());
+    }
+
+    #[test]
+    fn is_bounded() {
+        let mut history = History::new(0);
+        for state in 1..=HISTORY_LIMIT + 10 {
+            history.push(state);
+        }
+        let mut undone = 0;
+        while history.undo().is_some() {
+            undone += 1;
+        }
+        assert_eq!(undone, HISTORY_LIMIT);
+        assert_eq!(history.present(), &10);
+    }
+}
@@ -0,0 +1,303 @@
+//! Import of lists kept in spreadsheets: CSV, or tab separated values as
+//! copied from a spreadsheet, with a row per musical.
+//!
+//! The columns are found by their header (title, rating, seen or status, in
+//! any order), else taken in that order. Titles are matched against the
+//! catalog; the rows which don't match a single musical exactly are to be
+//! reviewed before being imported.
+use crate::model::{CustomMusical, ListItem, MusicaList, RatingScale, Status};
+use crate::search::{SearchIndex, EXACT_SCORE};
+
+/// Number of candidates kept for the titles to review.
+const CANDIDATES: usize = 5;
+
+const TITLE_HEADERS: [&str; 4] = ["title", "name", "musical", "show"];
+const RATING_HEADERS: [&str; 4] = ["rating", "score", "stars", "note"];
+const STATUS_HEADERS: [&str; 4] = ["status", "seen", "viewed", "watched"];
+
+/// How the title of a row matched the catalog.
+#[derive(Debug, Clone, PartialEq)]
+pub enum TitleMatch {
+    /// The only musical named so, or aliased so.
+    Exact(u64),
+    /// Candidates, best first.
+    Ambiguous(Vec<u64>),
+    NotFound,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ImportRow {
+    pub title: String,
+    pub found: TitleMatch,
+    /// Musical the row is imported as, the best match until reviewed. None
+    /// imports it as a musical missing from the catalog.
+    pub musical_id: Option<u64>,
+    pub status: Status,
+    /// On the scale of the list imported into.
+    pub rating: u8,
+}
+
+impl ImportRow {
+    pub fn needs_review(&self) -> bool {
+        !matches!(self.found, TitleMatch::Exact(_))
+    }
+}
+
+/// Tabs when the first line has some, else the most frequent of `;` and `,`.
+fn delimiter(text: &str) -> u8 {
+    let first_line = text.lines().next().unwrap_or_default();
+    let count = |c| first_line.matches(c).count();
+    if count('\t') > 0 {
+        b'\t'
+    } else if count(';') > count(',') {
+        b';'
+    } else {
+        b','
+    }
+}
+
+/// `cell` as a rating on `scale`: stars (★★★½), a fraction (4/5) or a
+/// number on the scale (7, 3.5). A rating of 0 is no rating, like an empty
+/// cell.
+fn parse_rating(cell: &str, scale: RatingScale) -> Option<u8> {
+    let cell = cell.trim();
+    let (value, max) = if cell.contains('★') || cell.contains('½') {
+        let stars = cell.matches('★').count() as f64;
+        let half = if cell.contains('½') { 0.5 } else { 0.0 };
+        (stars + half, 5.0)
+    } else {
+        let number = |text: &str| text.trim().replace(',', ".").parse::<f64>().ok();
+        match cell.split_once('/') {
+            Some((value, max)) => (number(value)?, number(max)?),
+            None => (number(cell)?, scale.max() as f64),
+        }
+    };
+    (max > 0.0 && value >= 0.0)
+        .then(|| scale.clamp((value / max * scale.max() as f64).round() as u8))
+        .filter(|rating| *rating > 0)
+}
+
+fn parse_status(cell: &str) -> Option<Status> {
+    let cell = cell.trim().to_lowercase();
+    match cell.as_str() {
+        "yes" | "y" | "true" | "x" | "1" | "✓" | "✔" | "viewed" => Some(Status::Seen),
+        "no" | "n" | "false" | "0" => Some(Status::WantToSee),
+        _ => Status::ALL
+            .into_iter()
+            .find(|status| status.name() == cell || status.label() == cell),
+    }
+}
+
+fn match_title(title: &str, index: &SearchIndex) -> TitleMatch {
+    let candidates = index.search_scored(title, CANDIDATES);
+    let exact: Vec<u64> = candidates
+        .iter()
+        .filter(|(score, _)| *score >= EXACT_SCORE)
+        .map(|(_, id)| *id)
+        .collect();
+    match exact[..] {
+        [id] => TitleMatch::Exact(id),
+        [] if candidates.is_empty() => TitleMatch::NotFound,
+        [] => TitleMatch::Ambiguous(candidates.into_iter().map(|(_, id)| id).collect()),
+        _ => TitleMatch::Ambiguous(exact),
+    }
+}
+
+/// Rows of `text`, their titles matched in `index` and their ratings moved
+/// to `scale`. Rows without a title are skipped. A row without a status is
+/// seen when it has a rating.
+pub fn parse(
+    text: &str,
+    index: &SearchIndex,
+    scale: RatingScale,
+) -> Result<Vec<ImportRow>, csv::Error> {
+    let records = csv::ReaderBuilder::new()
+        .has_headers(false)
+        .flexible(true)
+        .delimiter(delimiter(text))
+        .from_reader(text.as_bytes())
+        .into_records()
+        .collect::<Result<Vec<_>, _>>()?;
+    let column = |headers: &csv::StringRecord, names: [&str; 4]| {
+        headers
+            .iter()
+            .position(|header| names.contains(&header.trim().to_lowercase().as_str()))
+    };
+    let (title, rating, status, skip) = match records.first() {
+        Some(headers) if column(headers, TITLE_HEADERS).is_some() => (
+            column(headers, TITLE_HEADERS),
+            column(headers, RATING_HEADERS),
+            column(headers, STATUS_HEADERS),
+            1,
+        ),
+        _ => (Some(0), Some(1), Some(2), 0),
+    };
+    let cell = |record: &csv::StringRecord, column: Option<usize>| {
+        column
+            .and_then(|column| record.get(column))
+            .map(str::trim)
+            .filter(|cell| !cell.is_empty())
+            .map(str::to_string)
+    };
+    Ok(records
+        .iter()
+        .skip(skip)
+        .filter_map(|record| {
+            let title = cell(record, title)?;
+            let rating = cell(record, rating).and_then(|cell| parse_rating(&cell, scale));
+            let status = cell(record, status)
+                .and_then(|cell| parse_status(&cell))
+                .unwrap_or(if rating.is_some() {
+                    Status::Seen
+                } else {
+                    Status::WantToSee
+                });
+            let found = match_title(&title, index);
+            let musical_id = match &found {
+                TitleMatch::Exact(id) => Some(*id),
+                TitleMatch::Ambiguous(candidates) => candidates.first().copied(),
+                TitleMatch::NotFound => None,
+            };
+            Some(ImportRow {
+                title,
+                found,
+                musical_id,
+                status,
+                rating: rating.unwrap_or(0),
+            })
+        })
+        .collect())
+}
+
+/// `list` with `rows` added after its items.
+pub fn import(list: &MusicaList, rows: &[ImportRow]) -> MusicaList {
+    let mut list = list.clone();
+    for row in rows {
+        list.items.push(ListItem {
+            id: list.next_item_id(),
+            musical_id: row.musical_id.unwrap_or(0),
+            status: row.status,
+            rating: row.rating,
+            review: None,
+            viewings: vec![],
+            tags: vec![],
+            custom: match row.musical_id {
+                Some(_) => None,
+                None => Some(CustomMusical {
+                    title: row.title.clone(),
+                    url: None,
+                }),
+            },
+        });
+    }
+    list
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::Musical;
+
+    fn index() -> SearchIndex {
+        let musical = |id, name: &str, label: Option<&str>, url: &str| Musical {
+            id,
+            name: name.to_string(),
+            label: label.map(str::to_string),
+            url: url.to_string(),
+            ..Default::default()
+        };
+        SearchIndex::new(&[
+            musical(1, "Cats", None, "Cats (musical)"),
+            musical(2, "42nd Street", Some("film"), "42nd Street (film)"),
+            musical(3, "42nd Street", Some("musical"), "42nd Street (musical)"),
+            musical(4, "Les Misérables", None, "Les Misérables (musical)"),
+        ])
+    }
+
+    fn parse_ten(text: &str) -> Vec<ImportRow> {
+        parse(text, &index(), RatingScale::Ten).unwrap()
+    }
+
+    #[test]
+    fn reads_columns_by_header() {
+        let rows = parse_ten("Seen,Show,Rating\nyes,Cats,8\nno,les miserables,\n");
+        assert_eq!(
+            rows,
+            vec![
+                ImportRow {
+                    title: "Cats".to_string(),
+                    found: TitleMatch::Exact(1),
+                    musical_id: Some(1),
+                    status: Status::Seen,
+                    rating: 8,
+                },
+                ImportRow {
+                    title: "les miserables".to_string(),
+                    found: TitleMatch::Exact(4),
+                    musical_id: Some(4),
+                    status: Status::WantToSee,
+                    rating: 0,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn reads_columns_in_order_without_header() {
+        let rows = parse_ten("Cats;4/5;booked\nLes Mis;;\n");
+        assert_eq!(rows[0].rating, 8);
+        assert_eq!(rows[0].status, Status::Booked);
+        assert_eq!(rows[1].status, Status::WantToSee);
+        let pasted = parse_ten("Cats\t3,5\nCats\t★★★½\n");
+        assert_eq!(pasted[0].rating, 4);
+        assert_eq!(pasted[0].status, Status::Seen);
+        assert_eq!(pasted[1].rating, 7);
+    }
+
+    #[test]
+    fn flags_ambiguous_titles() {
+        let rows = parse_ten("title\n42nd Street\nLes Mis\nCat\nWicked\n\n");
+        assert_eq!(rows[0].found, TitleMatch::Ambiguous(vec![2, 3]));
+        assert_eq!(rows[0].musical_id, Some(2));
+        assert_eq!(rows[1].found, TitleMatch::Ambiguous(vec![4]));
+        assert_eq!(rows[2].found, TitleMatch::Ambiguous(vec![1]));
+        assert_eq!(rows[3].found, TitleMatch::NotFound);
+        assert_eq!(rows[3].musical_id, None);
+        assert!(rows.iter().all(ImportRow::needs_review));
+        assert_eq!(rows.len(), 4);
+    }
+
+    #[test]
+    fn converts_ratings_to_the_scale() {
+        assert_eq!(parse_rating("8/10", RatingScale::Five), Some(4));
+        assert_eq!(parse_rating("4,5", RatingScale::Ten), Some(5));
+        assert_eq!(parse_rating("12", RatingScale::Ten), Some(10));
+        assert_eq!(parse_rating("★★", RatingScale::Hundred), Some(40));
+        assert_eq!(parse_rating("great", RatingScale::Ten), None);
+        assert_eq!(parse_rating("3/0", RatingScale::Ten), None);
+    }
+
+    #[test]
+    fn reads_a_zero_rating_as_no_rating() {
+        assert_eq!(parse_rating("0", RatingScale::Ten), None);
+        assert_eq!(parse_rating("0/5", RatingScale::Ten), None);
+        let rows = parse_ten("Cats,0,\nCats,0,yes\n");
+        assert_eq!(rows[0].status, Status::WantToSee);
+        assert_eq!(rows[0].rating, 0);
+        assert_eq!(rows[1].status, Status::Seen);
+    }
+
+    #[test]
+    fn imports_rows_after_the_items() {
+        let list = MusicaList {
+            rating_scale: RatingScale::Ten,
+            ..Default::default()
+        };
+        let list = import(&list, &parse_ten("Cats,8,yes\nWicked,,\n"));
+        let ids: Vec<u64> = list.items.iter().map(|item| item.id).collect();
+        assert_eq!(ids, vec![1, 2]);
+        assert_eq!(list.items[0].musical_id, 1);
+        assert_eq!(list.items[0].rating, 8);
+        assert_eq!(list.items[1].display_name(&[]), "Wicked");
+    }
+}
@@ -0,0 +1,12 @@
+pub mod catalog;
+pub mod codec;
+pub mod compare;
+pub mod history;
+pub mod import;
+pub mod merge;
+pub mod migration;
+pub mod model;
+pub mod musicals;
+pub mod recommend;
+pub mod search;
+pub mod view;
@@ -1,12 +1,21 @@
-mod model;
-mod musicals;
 use base64::{engine::general_purpose, Engine as _};
+use gloo::file::callbacks::{read_as_text, FileReader};
 use gloo::storage::LocalStorage;
 use gloo_storage::Storage;
-use web_sys::{HtmlInputElement, InputEvent};
+use musicalist::codec;
+use musicalist::compare::{compare, Row};
+use musicalist::history::History;
+use musicalist::import::{self, ImportRow, TitleMatch};
+use musicalist::merge::{merge, MergePolicy, RatingPolicy, StatusPolicy};
+use musicalist::model::*;
+use musicalist::musicals::MUSICALS;
+use musicalist::recommend::{recommend, Reason};
+use musicalist::search::SEARCH_INDEX;
+use musicalist::view::{SortKey, View};
+use std::collections::HashSet;
+use web_sys::HtmlInputElement;
 use yew::prelude::*;
 use yew_router::prelude::*;
-include!("musicals.rs");
 
 #[derive(Debug, Clone, PartialEq, Routable)]
 pub enum Route {
@@ -17,6 +26,26 @@ pub enum Route {
 fn switch(routes: Route) -> Html {
     match routes {
         Route::Home => html! {
+            <Page />
+        },
+    }
+}
+
+/// The comparison of lists when the query names users to compare, the group
+/// leaderboard when asked, else the list itself.
+#[function_component(Page)]
+fn page() -> Html {
+    let query = use_location()
+        .and_then(|location| location.query::<Query>().ok())
+        .unwrap_or_default();
+    match (query.compared_users(), query.group) {
+        (Some(users), _) => html! {
+            <Compare {users}/>
+        },
+        (None, Some(true)) => html! {
+            <Group />
+        },
+        _ => html! {
             <Home />
         },
     }
@@ -31,31 +60,19 @@ fn app() -> Html {
     }
 }
 
-static CURRENT_VERSION: u8 = 1;
+fn empty_list() -> MusicaList {
+    MusicaList::default()
+}
 
-fn get_list_value(content: &Option<String>) -> MusicaList {
-    let default_list = MusicaList {
-        version: CURRENT_VERSION,
-        author: "".to_string(),
-        items: vec![],
-    };
-    content.clone().map_or(default_list.clone(), |content| {
-        general_purpose::STANDARD
-            .decode(content)
-            .map(|bytes| rmp_serde::from_read(&bytes[..]).unwrap_or(default_list.clone()))
-            .unwrap_or(default_list)
-    })
+fn get_list_value(content: &Option<String>) -> Result<MusicaList, codec::DecodeError> {
+    match content.as_deref() {
+        None | Some("") => Ok(empty_list()),
+        Some(content) => codec::decode(content),
+    }
 }
 
 fn get_content(list: &MusicaList) -> String {
-    let val = MusicaList {
-        version: list.version,
-        author: list.author.clone(),
-        items: list.items.clone(),
-    };
-    let str = rmp_serde::to_vec(&val).unwrap();
-    // convert str to base64
-    general_purpose::STANDARD.encode(str)
+    codec::encode(list).unwrap()
 }
 
 fn get_url(list: &MusicaList) -> (String, String) {
@@ -63,6 +80,14 @@ fn get_url(list: &MusicaList) -> (String, String) {
     (format!("?content={}", str), str)
 }
 
+/// Content of a sharing url, or `link` itself when it is the bare content.
+fn link_content(link: &str) -> String {
+    web_sys::Url::new(link.trim())
+        .ok()
+        .and_then(|url| url.search_params().get("content"))
+        .unwrap_or_else(|| link.trim().to_string())
+}
+
 fn get_users() -> Users {
     let default_users = Users {
         version: 1,
@@ -115,7 +140,7 @@ fn get_content_local_storage(user: &Option<String>) -> String {
     let content_local_storage_key = "content";
     if let Some(user) = user {
         log::info!("load user: {}", user);
-        LocalStorage::get(&format!("{}/{}", content_local_storage_key, user)).unwrap_or_default()
+        LocalStorage::get(format!("{}/{}", content_local_storage_key, user)).unwrap_or_default()
     } else {
         LocalStorage::get(content_local_storage_key).unwrap_or_default()
     }
@@ -125,7 +150,7 @@ fn save_user_content_to_local_storage(list_value: &MusicaList, content: &String)
     let content_local_storage_key = "content";
     let user_key = format!("{}/{}", content_local_storage_key, &list_value.author);
     log::info!("save user: {}", user_key);
-    LocalStorage::set(user_key, &content).unwrap();
+    LocalStorage::set(user_key, content).unwrap();
 }
 
 fn add_user_and_content(list_value: &MusicaList, content: &String) {
@@ -143,6 +168,353 @@ fn delete_user_content(user: &String) {
     LocalStorage::delete(&user_key);
 }
 
+const HISTORY_LOCAL_STORAGE_KEY: &str = "history";
+
+/// The stored history, if it was left on `list`, else a new one.
+fn load_history(list: &MusicaList) -> History<MusicaList> {
+    match LocalStorage::get::<History<MusicaList>>(HISTORY_LOCAL_STORAGE_KEY) {
+        Ok(history) if history.present() == list => history,
+        _ => History::new(list.clone()),
+    }
+}
+
+fn save_history(history: &History<MusicaList>) {
+    if let Err(err) = LocalStorage::set(HISTORY_LOCAL_STORAGE_KEY, history) {
+        log::info!("could not save history: {}", err);
+    }
+}
+
+#[derive(Properties, PartialEq)]
+struct MusicalPickerProps {
+    musical_id: u64,
+    /// Title of the musical when it is not in the catalog.
+    custom: Option<String>,
+    onselect: Callback<u64>,
+    /// Called with what was typed to use it as a musical missing from the catalog.
+    oncustom: Callback<String>,
+}
+
+/// Number of musicals suggested to add to the list.
+const RECOMMENDATIONS: usize = 5;
+
+/// Maximum number of suggestions shown by the musical picker.
+const PICKER_SUGGESTIONS: usize = 10;
+
+/// Type-ahead replacement of a select listing every musical: suggests the
+/// musicals matching what is typed, by name or alias, followed by what is
+/// typed itself for musicals missing from the catalog.
+#[function_component(MusicalPicker)]
+fn musical_picker(props: &MusicalPickerProps) -> Html {
+    let search = use_state(String::new);
+    let highlighted = use_state(|| 0usize);
+    let suggestions = SEARCH_INDEX.search(&search, PICKER_SUGGESTIONS);
+    let custom = Some(search.trim().to_string()).filter(|custom| !custom.is_empty());
+    let options = suggestions.len() + usize::from(custom.is_some());
+    let current = match &props.custom {
+        Some(title) => title.clone(),
+        None => MUSICALS
+            .iter()
+            .find(|m| m.id == props.musical_id)
+            .map(|m| m.display_name())
+            .unwrap_or_default(),
+    };
+
+    let select = {
+        let search = search.clone();
+        let highlighted = highlighted.clone();
+        let onselect = props.onselect.clone();
+        move |musical_id: u64| {
+            search.set(String::new());
+            highlighted.set(0);
+            onselect.emit(musical_id);
+        }
+    };
+
+    let select_custom = {
+        let search = search.clone();
+        let highlighted = highlighted.clone();
+        let oncustom = props.oncustom.clone();
+        move |title: String| {
+            search.set(String::new());
+            highlighted.set(0);
+            oncustom.emit(title);
+        }
+    };
+
+    let oninput = {
+        let search = search.clone();
+        let highlighted = highlighted.clone();
+        Callback::from(move |e: InputEvent| {
+            search.set(e.target_unchecked_into::<HtmlInputElement>().value());
+            highlighted.set(0);
+        })
+    };
+
+    let onkeydown = {
+        let search = search.clone();
+        let highlighted = highlighted.clone();
+        let suggestions = suggestions.clone();
+        let custom = custom.clone();
+        let select = select.clone();
+        let select_custom = select_custom.clone();
+        Callback::from(move |e: KeyboardEvent| match e.key().as_str() {
+            "ArrowDown" if *highlighted + 1 < options => {
+                e.prevent_default();
+                highlighted.set(*highlighted + 1);
+            }
+            "ArrowUp" if *highlighted > 0 => {
+                e.prevent_default();
+                highlighted.set(*highlighted - 1);
+            }
+            "Enter" => {
+                if let Some(musical_id) = suggestions.get(*highlighted) {
+                    select(*musical_id);
+                } else if let Some(custom) = &custom {
+                    select_custom(custom.clone());
+                }
+            }
+            "Escape" => {
+                search.set(String::new());
+                highlighted.set(0);
+            }
+            _ => {}
+        })
+    };
+
+    // suggestions are picked on mouse down, before the input loses the focus
+    let onblur = {
+        let search = search.clone();
+        let highlighted = highlighted.clone();
+        Callback::from(move |_: FocusEvent| {
+            search.set(String::new());
+            highlighted.set(0);
+        })
+    };
+
+    html! {
+        <span class={"picker"}>
+            <input type="search" placeholder={current.clone()} title={current}
+                value={(*search).clone()} {oninput} {onkeydown} {onblur}/>
+            if options > 0 {
+                <ul>
+                { for suggestions.iter().enumerate().map(|(i, musical_id)| {
+                    let musical = MUSICALS.iter().find(|m| m.id == *musical_id);
+                    let select = select.clone();
+                    let musical_id = *musical_id;
+                    html! {
+                        <li class={ if i == *highlighted { "highlighted" } else { "" } }
+                            onmousedown={move |_| select(musical_id)}>
+                            { musical.map(|m| m.display_name()).unwrap_or_default() }
+                        </li>
+                    }
+                })}
+                if let Some(custom) = custom {
+                    <li class={ if *highlighted == suggestions.len() { "highlighted" } else { "" } }
+                        onmousedown={
+                            let custom = custom.clone();
+                            move |_| select_custom(custom.clone())
+                        }>
+                        { format!("＋ “{}”, not in the catalog", custom) }
+                    </li>
+                }
+                </ul>
+            }
+        </span>
+    }
+}
+
+#[derive(Properties, PartialEq)]
+struct RatingInputProps {
+    scale: RatingScale,
+    rating: u8,
+    onchange: Callback<u8>,
+}
+
+/// Sets a rating in one action, by clicking a star or typing the number.
+#[function_component(RatingInput)]
+fn rating_input(props: &RatingInputProps) -> Html {
+    let RatingInputProps {
+        scale,
+        rating,
+        onchange,
+    } = props;
+    let (scale, rating) = (*scale, *rating);
+    let onnumber = {
+        let onchange = onchange.clone();
+        Callback::from(move |e: Event| {
+            if let Ok(rating) = e
+                .target_unchecked_into::<HtmlInputElement>()
+                .value()
+                .parse()
+            {
+                onchange.emit(rating);
+            }
+        })
+    };
+    html! {
+        <span class={"rating"}>
+            { for scale.stars(rating).chars().zip(1u8..).map(|(star, i)| {
+                let onchange = onchange.clone();
+                html! {
+                    <button onclick={move |_| onchange.emit(scale.click_star(rating, i))}
+                        title={ scale.format(RatingScale::Five.convert(i, scale)) }>
+                        { star }
+                    </button>
+                }
+            })}
+            <input type="number" min="0" max={ scale.max().to_string() }
+                value={ rating.to_string() } onchange={onnumber}/>
+        </span>
+    }
+}
+
+/// The lists stored for `users` which can be decoded.
+fn stored_lists(users: &[String]) -> Vec<MusicaList> {
+    users
+        .iter()
+        .filter_map(|user| codec::decode(&get_content_local_storage(&Some(user.clone()))).ok())
+        .collect()
+}
+
+#[derive(Properties, PartialEq)]
+struct CompareProps {
+    users: Vec<String>,
+}
+
+/// The lists stored for `users`, side by side.
+#[function_component(Compare)]
+fn compare_lists(props: &CompareProps) -> Html {
+    let lists = stored_lists(&props.users);
+    let comparison = compare(&lists);
+    let authors: Vec<String> = lists.iter().map(|list| list.author.clone()).collect();
+    let rating = |l: usize, item: &ListItem| {
+        let scale = lists[l].rating_scale;
+        html! {
+            <span title={ scale.format(item.aggregate_rating()) }>
+                { scale.stars(item.aggregate_rating()) }
+            </span>
+        }
+    };
+    let header = html! {
+        <>
+        <th>{ "Musical" }</th>
+        { for authors.iter().map(|author| html! { <th>{ author }</th> }) }
+        </>
+    };
+    html! {
+        <>
+        <p>{ "Comparing " }{ authors.join(", ") }</p>
+        if lists.len() < 2 {
+            <p>{ "Pick at least two users to compare." }</p>
+        } else {
+            <p>
+            { match comparison.agreement() {
+                Some(agreement) => format!("Agreement: {}%", agreement),
+                None => "No musical was seen by two of them yet.".to_string(),
+            } }
+            </p>
+            <h3>{ "Seen by everyone" }</h3>
+            <table class={"center"}>
+                <tr>{ header.clone() }<th>{ "Difference" }</th></tr>
+                { for comparison.seen_by_all().into_iter().map(|row| html! {
+                    <tr>
+                        <td>{ row.display_name(&MUSICALS) }</td>
+                        { for row.items.iter().enumerate().map(|(l, item)| html! {
+                            <td>{ for item.map(|item| rating(l, item)) }</td>
+                        })}
+                        <td title="between the highest and lowest ratings">
+                            { row.spread().map(|spread| format!("{:.1}★", spread as f32 / 20.0)).unwrap_or_default() }
+                        </td>
+                    </tr>
+                })}
+            </table>
+            <h3>{ "Seen by only some" }</h3>
+            <table class={"center"}>
+                <tr>{ header }</tr>
+                { for comparison.seen_by_some().into_iter().map(|row| html! {
+                    <tr>
+                        <td>{ row.display_name(&MUSICALS) }</td>
+                        { for row.items.iter().enumerate().map(|(l, item)| html! {
+                            <td>
+                            if let Some(item) = item {
+                                if item.status == Status::Seen {
+                                    { rating(l, item) }
+                                } else {
+                                    <span title={ item.status.label() }>{ item.status.icon() }</span>
+                                }
+                            }
+                            </td>
+                        })}
+                    </tr>
+                })}
+            </table>
+        }
+        <p><a href={"/musicalist/"}>{ "back to my list" }</a></p>
+        </>
+    }
+}
+
+/// Leaderboard of the lists of every user, e.g. to pick an outing.
+#[function_component(Group)]
+fn group() -> Html {
+    let lists = stored_lists(&get_users().items);
+    let comparison = compare(&lists);
+    let authors: Vec<String> = lists.iter().map(|list| list.author.clone()).collect();
+    // who wants to see the musical of `row`, or booked it
+    let wanters = |row: &Row| {
+        row.items
+            .iter()
+            .zip(authors.iter())
+            .filter(|(item, _)| {
+                item.is_some_and(|item| matches!(item.status, Status::WantToSee | Status::Booked))
+            })
+            .map(|(_, author)| author.clone())
+            .collect::<Vec<_>>()
+            .join(", ")
+    };
+    html! {
+        <>
+        <p>{ format!("Group of {} lists: ", lists.len()) }{ authors.join(", ") }</p>
+        <h3>{ "Leaderboard" }</h3>
+        <table class={"center"}>
+            <tr>
+                <th>{ "#" }</th>
+                <th>{ "Musical" }</th>
+                <th>{ "Average" }</th>
+                <th>{ "Viewers" }</th>
+            </tr>
+            { for comparison.leaderboard().into_iter().enumerate().map(|(rank, row)| {
+                let average = row.average().unwrap_or_default();
+                html! {
+                    <tr>
+                        <td>{ rank + 1 }</td>
+                        <td>{ row.display_name(&MUSICALS) }</td>
+                        <td title={ RatingScale::Hundred.format(average) }>
+                            { RatingScale::Hundred.stars(average) }
+                        </td>
+                        <td>{ row.seen_by() }</td>
+                    </tr>
+                }
+            })}
+        </table>
+        <h3>{ "Most wanted, unseen" }</h3>
+        <table class={"center"}>
+            <tr>
+                <th>{ "Musical" }</th>
+                <th>{ "Wanted by" }</th>
+            </tr>
+            { for comparison.most_wanted().into_iter().map(|row| html! {
+                <tr>
+                    <td>{ row.display_name(&MUSICALS) }</td>
+                    <td title={ wanters(row) }>{ row.wanted_by() }</td>
+                </tr>
+            })}
+        </table>
+        <p><a href={"/musicalist/"}>{ "back to my list" }</a></p>
+        </>
+    }
+}
+
 #[function_component(Home)]
 fn home() -> Html {
     let bookmark_url = use_state(|| "".to_string());
@@ -152,8 +524,6 @@ fn home() -> Html {
     let content_local_storage_key = "content";
     let current_location = use_location().unwrap();
 
-    let location = yew_hooks::use_location();
-
     let content_local_storage: String = get_content_local_storage(
         &current_location
             .query::<Query>()
@@ -164,45 +534,100 @@ fn home() -> Html {
         .map_or(Some(content_local_storage.clone()), |query| query.content);
 
     let content = {
-        if content == None {
+        if content.is_none() {
             Some(content_local_storage)
         } else {
             content
         }
     };
 
-    let trigger = use_force_update();
-
     let edit = current_location
         .query::<Query>()
         .map_or(Some(false), |query| query.edit);
 
-    let list_value: MusicaList = get_list_value(&content);
-    content.map(|c| add_user_and_content(&list_value, &c));
+    let details = current_location
+        .query::<Query>()
+        .ok()
+        .and_then(|query| query.details);
+
+    let content_from_url = current_location
+        .query::<Query>()
+        .is_ok_and(|query| query.content.is_some());
+
+    let view = current_location
+        .query::<Query>()
+        .map(|query| View::from_query(&query))
+        .unwrap_or_default();
+
+    // query of the urls of the page, keeping its modes and view
+    let page_query = view.to_query(Query {
+        edit,
+        details,
+        ..Default::default()
+    });
+
+    // a list which can't be decoded must not overwrite what is stored
+    let (list_value, decode_error) = match get_list_value(&content) {
+        Ok(list_value) => {
+            if let Some(c) = &content {
+                add_user_and_content(&list_value, c);
+            }
+            (list_value, None)
+        }
+        Err(err) => {
+            log::info!("could not decode content: {}", err);
+            (empty_list(), Some(err))
+        }
+    };
+    let salvaged = decode_error
+        .as_ref()
+        .and_then(|_| content.as_deref().and_then(codec::salvage));
+    // the salvaged list is only shown until it is kept
+    let show_salvaged = use_state(|| false);
 
     let list = use_state(|| list_value.clone());
 
-    macro_rules! update_list_fn {
-        ($list:expr, $list_out:expr) => {{
-            let bookmark_url = bookmark_url.clone();
-            let navigator = navigator.clone();
-            move |_| {
-                let list_out = $list_out;
-                let (new_url, content) = get_url(&list_out);
-                bookmark_url.set(new_url);
-                LocalStorage::set(content_local_storage_key, &content).unwrap();
-                add_user_and_content(&list_out, &content);
-                let _ = navigator.push_with_query(
-                    &Route::Home,
-                    &Query {
-                        content: Some(content),
-                        edit,
-                        user: None,
-                    },
-                );
-                add_user(&list_out.author);
-                $list.set(list_out);
+    let history = use_state(|| load_history(&list_value));
+
+    // show `list_out` and save it to the url and local storage
+    let show_list = {
+        let page_query = page_query.clone();
+        let bookmark_url = bookmark_url.clone();
+        let navigator = navigator.clone();
+        let list = list.clone();
+        move |list_out: &MusicaList| {
+            let (new_url, content) = get_url(list_out);
+            bookmark_url.set(new_url);
+            LocalStorage::set(content_local_storage_key, &content).unwrap();
+            add_user_and_content(list_out, &content);
+            let _ = navigator.replace_with_query(
+                &Route::Home,
+                &Query {
+                    content: Some(content),
+                    ..page_query.clone()
+                },
+            );
+            list.set(list_out.clone());
+        }
+    };
+
+    let set_list = {
+        let history = history.clone();
+        let show_list = show_list.clone();
+        Callback::from(move |list_out: MusicaList| {
+            let mut history_out = (*history).clone();
+            if history_out.push(list_out.clone()) {
+                save_history(&history_out);
+                history.set(history_out);
             }
+            show_list(&list_out);
+        })
+    };
+
+    macro_rules! update_list_fn {
+        ($list_out:expr) => {{
+            let set_list = set_list.clone();
+            move |_| set_list.emit($list_out)
         }};
     }
 
@@ -212,8 +637,6 @@ fn home() -> Html {
         f: impl Fn(&ListItem) -> ListItem,
     ) -> MusicaList {
         MusicaList {
-            version: list.version,
-            author: list.author.clone(),
             items: list
                 .items
                 .iter()
@@ -227,33 +650,127 @@ fn home() -> Html {
                     },
                 )
                 .collect(),
+            ..list.clone()
         }
     }
 
     let change_edit = {
+        let page_query = page_query.clone();
         let navigator = navigator.clone();
         let list = list.clone();
         move |_| {
             let (_, content) = get_url(&list);
-            let _ = navigator.push_with_query(
+            let _ = navigator.replace_with_query(
                 &Route::Home,
                 &Query {
                     content: Some(content),
-                    edit: Some(!(edit == Some(true))),
-                    user: None,
+                    edit: Some(edit != Some(true)),
+                    ..page_query.clone()
                 },
             );
         }
     };
 
-    let clear_all_content = || {
-        get_content(&MusicaList {
-            version: CURRENT_VERSION,
-            author: "".to_string(),
-            items: vec![],
+    let set_view = {
+        let navigator = navigator.clone();
+        let list = list.clone();
+        let page_query = page_query.clone();
+        Callback::from(move |view: View| {
+            let (_, content) = get_url(&list);
+            let _ = navigator.replace_with_query(
+                &Route::Home,
+                &view.to_query(Query {
+                    content: Some(content),
+                    ..page_query.clone()
+                }),
+            );
         })
     };
 
+    let sort_by = |key: SortKey| {
+        let set_view = set_view.clone();
+        let view = view.clone();
+        move |_| set_view.emit(view.toggle_sort(key))
+    };
+
+    let change_unseen_only = {
+        let set_view = set_view.clone();
+        let view = view.clone();
+        move |_| {
+            set_view.emit(View {
+                unseen_only: !view.unseen_only,
+                ..view.clone()
+            })
+        }
+    };
+
+    let change_status_filter = {
+        let set_view = set_view.clone();
+        let view = view.clone();
+        move |e: Event| {
+            let name = e.target_unchecked_into::<HtmlInputElement>().value();
+            set_view.emit(View {
+                status: Status::from_name(&name),
+                ..view.clone()
+            })
+        }
+    };
+
+    let change_min_rating = {
+        let set_view = set_view.clone();
+        let view = view.clone();
+        move |e: Event| {
+            let min_rating = e.target_unchecked_into::<HtmlInputElement>().value();
+            set_view.emit(View {
+                min_rating: min_rating.parse().ok().filter(|min_rating| *min_rating > 0),
+                ..view.clone()
+            })
+        }
+    };
+
+    let change_tag_filter = {
+        let set_view = set_view.clone();
+        let view = view.clone();
+        move |e: Event| {
+            let tag = e.target_unchecked_into::<HtmlInputElement>().value();
+            set_view.emit(View {
+                tag: Some(tag).filter(|tag| !tag.is_empty()),
+                ..view.clone()
+            })
+        }
+    };
+
+    let filter_tag = |tag: &str| {
+        let set_view = set_view.clone();
+        let view = view.clone();
+        let tag = tag.to_string();
+        move |_| {
+            set_view.emit(View {
+                tag: Some(tag.clone()),
+                ..view.clone()
+            })
+        }
+    };
+
+    let change_details = {
+        let page_query = page_query.clone();
+        let navigator = navigator.clone();
+        let list = list.clone();
+        move |_| {
+            let (_, content) = get_url(&list);
+            let _ = navigator.replace_with_query(
+                &Route::Home,
+                &Query {
+                    content: Some(content),
+                    details: Some(details != Some(true)),
+                    ..page_query.clone()
+                },
+            );
+        }
+    };
+
+    let clear_all_content = || get_content(&empty_list());
+
     let clear_all_url = || format!("?edit=true&content={}", clear_all_content());
 
     let delete_user = |user| {
@@ -261,12 +778,169 @@ fn home() -> Html {
         move |_| {
             delete_user(&user);
             // reload page:
-            let _ = navigator.push_with_query(
+            let _ = navigator.replace_with_query(
                 &Route::Home,
                 &Query {
                     content: Some(clear_all_content()),
                     edit: None,
                     user: Some("".to_string()),
+                    ..Default::default()
+                },
+            );
+        }
+    };
+
+    // users whose lists are picked to be compared
+    let compared = use_state(Vec::<String>::new);
+
+    let toggle_compared = |user: String| {
+        let compared = compared.clone();
+        move |_| {
+            let mut compared_out = (*compared).clone();
+            if compared_out.contains(&user) {
+                compared_out.retain(|compared| *compared != user);
+            } else {
+                compared_out.push(user.clone());
+            }
+            compared.set(compared_out);
+        }
+    };
+
+    let compare_users = {
+        let navigator = navigator.clone();
+        let compared = compared.clone();
+        move |_| {
+            let _ = navigator.push_with_query(&Route::Home, &Query::comparing(&compared));
+        }
+    };
+
+    // list being merged into ours, shown in a preview until it is applied
+    let merged = use_state(|| None::<Result<MusicaList, String>>);
+    let merge_policy = use_state(MergePolicy::default);
+
+    let preview_merge = {
+        let merged = merged.clone();
+        move |e: Event| {
+            let input = e.target_unchecked_into::<HtmlInputElement>();
+            let link = input.value();
+            input.set_value("");
+            if !link.trim().is_empty() {
+                merged.set(Some(
+                    codec::decode(&link_content(&link)).map_err(|err| err.to_string()),
+                ));
+            }
+        }
+    };
+
+    let change_rating_policy = {
+        let merge_policy = merge_policy.clone();
+        move |e: Event| {
+            let label = e.target_unchecked_into::<HtmlInputElement>().value();
+            if let Some(rating) = RatingPolicy::ALL.into_iter().find(|p| p.label() == label) {
+                merge_policy.set(MergePolicy {
+                    rating,
+                    ..*merge_policy
+                });
+            }
+        }
+    };
+
+    let change_status_policy = {
+        let merge_policy = merge_policy.clone();
+        move |e: Event| {
+            let label = e.target_unchecked_into::<HtmlInputElement>().value();
+            if let Some(status) = StatusPolicy::ALL.into_iter().find(|p| p.label() == label) {
+                merge_policy.set(MergePolicy {
+                    status,
+                    ..*merge_policy
+                });
+            }
+        }
+    };
+
+    // rows of a spreadsheet, reviewed before they are imported
+    let imported = use_state(|| None::<Result<Vec<ImportRow>, String>>);
+    // kept until the file is read, which dropping it would cancel
+    let file_reader = use_mut_ref(|| None::<FileReader>);
+
+    let read_import = {
+        let imported = imported.clone();
+        let scale = list.rating_scale;
+        Callback::from(move |text: String| {
+            imported.set(Some(
+                import::parse(&text, &SEARCH_INDEX, scale).map_err(|err| err.to_string()),
+            ))
+        })
+    };
+
+    let import_file = {
+        let read_import = read_import.clone();
+        move |e: Event| {
+            let input = e.target_unchecked_into::<HtmlInputElement>();
+            if let Some(file) = input.files().and_then(|files| files.get(0)) {
+                let read_import = read_import.clone();
+                let reader = read_as_text(&gloo::file::File::from(file), move |text| match text {
+                    Ok(text) => read_import.emit(text),
+                    Err(err) => log::info!("could not read file: {}", err),
+                });
+                *file_reader.borrow_mut() = Some(reader);
+            }
+            input.set_value("");
+        }
+    };
+
+    let import_pasted = {
+        let read_import = read_import.clone();
+        move |e: Event| {
+            let input = e.target_unchecked_into::<HtmlInputElement>();
+            read_import.emit(input.value());
+            input.set_value("");
+        }
+    };
+
+    let choose_import_match = |row: usize| {
+        let imported = imported.clone();
+        move |e: Event| {
+            let musical_id = e.target_unchecked_into::<HtmlInputElement>().value();
+            if let Some(Ok(mut rows)) = (*imported).clone() {
+                if let Some(row) = rows.get_mut(row) {
+                    row.musical_id = musical_id.parse().ok();
+                }
+                imported.set(Some(Ok(rows)));
+            }
+        }
+    };
+
+    let apply_import = {
+        let imported = imported.clone();
+        let list = list.clone();
+        let set_list = set_list.clone();
+        move |_| {
+            if let Some(Ok(rows)) = &*imported {
+                set_list.emit(import::import(&list, rows));
+            }
+            imported.set(None);
+        }
+    };
+
+    let cancel_import = {
+        let imported = imported.clone();
+        move |_| imported.set(None)
+    };
+
+    let cancel_merge = {
+        let merged = merged.clone();
+        move |_| merged.set(None)
+    };
+
+    let show_group = {
+        let navigator = navigator.clone();
+        move |_| {
+            let _ = navigator.push_with_query(
+                &Route::Home,
+                &Query {
+                    group: Some(true),
+                    ..Default::default()
                 },
             );
         }
@@ -274,163 +948,351 @@ fn home() -> Html {
 
     let delete = |id| {
         let list = list.clone();
-        update_list_fn!(
-            list,
-            MusicaList {
-                version: list.version,
-                author: list.author.clone(),
+        update_list_fn!({
+            let mut list_out = MusicaList {
                 items: list
                     .items
                     .iter()
                     .filter(|item| item.id != id)
                     .cloned()
                     .collect(),
+                ..(*list).clone()
+            };
+            list_out.prune_tags();
+            list_out
+        })
+    };
+
+    let set_status = |id: u64| {
+        let list = list.clone();
+        let set_list = set_list.clone();
+        Callback::from(move |e: Event| {
+            let name = e.target_unchecked_into::<HtmlInputElement>().value();
+            if let Some(status) = Status::from_name(&name) {
+                set_list.emit(update_item_in_list(&list, id, |item| ListItem {
+                    status,
+                    ..item.clone()
+                }))
             }
-        )
+        })
     };
 
-    let change_viewed = |id| {
+    let move_item = |id: u64, to: usize| {
         let list = list.clone();
-        update_list_fn!(
-            list,
-            update_item_in_list(&list, id, |item| ListItem {
-                viewed: !item.viewed,
-                ..item.clone()
+        update_list_fn!(list.moved(id, to))
+    };
+
+    let move_to_position = |id: u64| {
+        let list = list.clone();
+        let set_list = set_list.clone();
+        Callback::from(move |e: Event| {
+            let position = e.target_unchecked_into::<HtmlInputElement>().value();
+            if let Ok(position) = position.parse::<usize>() {
+                set_list.emit(list.moved(id, position.saturating_sub(1)));
+            }
+        })
+    };
+
+    // item being dragged over the others to be moved
+    let dragged = use_state(|| None::<u64>);
+
+    let drag_start = |id: u64| {
+        let dragged = dragged.clone();
+        move |_: DragEvent| dragged.set(Some(id))
+    };
+
+    let drop_on = |to: usize| {
+        let dragged = dragged.clone();
+        let list = list.clone();
+        let set_list = set_list.clone();
+        move |e: DragEvent| {
+            e.prevent_default();
+            if let Some(id) = *dragged {
+                set_list.emit(list.moved(id, to));
+            }
+            dragged.set(None);
+        }
+    };
+
+    // items whose detail row is shown
+    let expanded = use_state(HashSet::<u64>::new);
+
+    // suggestions from the lists of the other users, to add in one click,
+    // only computed again when what they depend on changes (not the reviews)
+    let recommendations = {
+        let list = (*list).clone();
+        let ratings: Vec<(u64, bool, Status, u8)> = list
+            .items
+            .iter()
+            .map(|item| {
+                (
+                    item.musical_id,
+                    item.custom.is_some(),
+                    item.status,
+                    item.aggregate_rating(),
+                )
             })
+            .collect();
+        let key = (
+            edit == Some(true),
+            list.author.clone(),
+            list.rating_scale,
+            ratings,
+        );
+        use_memo(
+            move |(edit, author, _, _)| {
+                if !*edit {
+                    return vec![];
+                }
+                let others: Vec<String> = get_users()
+                    .items
+                    .into_iter()
+                    .filter(|user| user != author)
+                    .collect();
+                recommend(&list, &stored_lists(&others), &MUSICALS, RECOMMENDATIONS)
+            },
+            key,
         )
     };
 
-    let move_item = |id: usize, delta: i8| {
-        /* swap id and new_index in list */
+    let toggle_details = |id: u64| {
+        let expanded = expanded.clone();
+        move |_| {
+            let mut expanded_out = (*expanded).clone();
+            if !expanded_out.remove(&id) {
+                expanded_out.insert(id);
+            }
+            expanded.set(expanded_out);
+        }
+    };
+
+    fn update_viewing_in_list(
+        list: &MusicaList,
+        id: u64,
+        index: usize,
+        f: impl Fn(&mut Viewing),
+    ) -> MusicaList {
+        update_item_in_list(list, id, |item| {
+            let mut item = item.clone();
+            if let Some(viewing) = item.viewings.get_mut(index) {
+                f(viewing);
+            }
+            item
+        })
+    }
+
+    fn input_text(e: Event) -> Option<String> {
+        let value = e.target_unchecked_into::<HtmlInputElement>().value();
+        Some(value.trim().to_string()).filter(|value| !value.is_empty())
+    }
+
+    let set_review = |id: u64| {
         let list = list.clone();
-        update_list_fn!(list, {
-            let new_index = id as i8 + delta;
-            let new_index = if new_index < 0 {
-                0
-            } else if new_index >= list.items.len() as i8 {
-                list.items.len() as i8 - 1
-            } else {
-                new_index
-            };
-            let mut items = list.items.clone();
-            items.swap(id as usize, new_index as usize);
-            MusicaList {
-                items,
-                author: list.author.clone(),
-                version: list.version,
+        let set_list = set_list.clone();
+        Callback::from(move |e: Event| {
+            let review = input_text(e);
+            set_list.emit(update_item_in_list(&list, id, |item| ListItem {
+                review: review.clone(),
+                ..item.clone()
+            }))
+        })
+    };
+
+    let add_viewing = |id: u64| {
+        let list = list.clone();
+        update_list_fn!(update_item_in_list(&list, id, |item| {
+            let mut item = item.clone();
+            item.viewings.push(Viewing::default());
+            item.status = Status::Seen;
+            item
+        }))
+    };
+
+    let delete_viewing = |id: u64, index: usize| {
+        let list = list.clone();
+        update_list_fn!(update_item_in_list(&list, id, |item| {
+            let mut item = item.clone();
+            if index < item.viewings.len() {
+                item.viewings.remove(index);
             }
+            item
+        }))
+    };
+
+    let set_viewing_field =
+        |id: u64, index: usize, field: fn(&mut Viewing) -> &mut Option<String>| {
+            let list = list.clone();
+            let set_list = set_list.clone();
+            Callback::from(move |e: Event| {
+                let value = input_text(e);
+                set_list.emit(update_viewing_in_list(&list, id, index, |viewing| {
+                    *field(viewing) = value.clone()
+                }))
+            })
+        };
+
+    let set_viewing_rating = |id: u64, index: usize| {
+        let list = list.clone();
+        let set_list = set_list.clone();
+        Callback::from(move |rating: u8| {
+            set_list.emit(update_viewing_in_list(&list, id, index, |viewing| {
+                viewing.rating = Some(list.rating_scale.clamp(rating))
+            }))
         })
     };
 
-    let update_rating = |id: u64, delta: i8| {
+    let set_rating = |id: u64| {
         let list = list.clone();
-        update_list_fn!(
-            list,
-            update_item_in_list(&list, id, |item| ListItem {
-                rating: {
-                    let new_rating = item.rating as i8 + delta;
-                    if new_rating < 0 {
-                        10
-                    } else if new_rating > 10 {
-                        0
-                    } else {
-                        new_rating as u8
-                    }
-                },
+        let set_list = set_list.clone();
+        Callback::from(move |rating: u8| {
+            set_list.emit(update_item_in_list(&list, id, |item| ListItem {
+                rating: list.rating_scale.clamp(rating),
                 ..item.clone()
-            })
-        )
+            }))
+        })
     };
 
-    let add_musical = {
+    let add_musical = |musical_id: u64| {
         let list = list.clone();
-        update_list_fn!(list, {
+        update_list_fn!({
             let mut items = list.items.clone();
             items.push(ListItem {
-                id: list.items.len() as u64 + 1,
-                musical_id: 1,
-                viewed: false,
+                id: list.next_item_id(),
+                musical_id,
+                status: Status::WantToSee,
                 rating: 0,
+                review: None,
+                viewings: vec![],
+                tags: vec![],
+                custom: None,
             });
             MusicaList {
-                version: list.version,
-                author: list.author.clone(),
                 items,
+                ..(*list).clone()
             }
         })
     };
 
-    let go = |i| {
-        let navigator = navigator.clone();
-        let list = list.clone();
-        let trigger = trigger.clone();
-        let current_location = current_location.clone();
+    let undo = {
+        let history = history.clone();
+        let show_list = show_list.clone();
         move |_| {
-            navigator.go(i);
-            trigger.force_update();
-            let query = current_location.query::<Query>().unwrap();
-            list.set(get_list_value(&query.content));
+            let mut history_out = (*history).clone();
+            if let Some(list_out) = history_out.undo() {
+                show_list(list_out);
+                save_history(&history_out);
+                history.set(history_out);
+            }
+        }
+    };
+
+    let redo = {
+        let history = history.clone();
+        let show_list = show_list.clone();
+        move |_| {
+            let mut history_out = (*history).clone();
+            if let Some(list_out) = history_out.redo() {
+                show_list(list_out);
+                save_history(&history_out);
+                history.set(history_out);
+            }
         }
     };
 
     let change_musical = |id: u64| {
         let list = list.clone();
-        let url = bookmark_url.clone();
-        let navigator = navigator.clone();
-        move |e: Event| {
-            let list_out = update_item_in_list(&list, id, |item| ListItem {
-                musical_id: e
-                    .target_unchecked_into::<HtmlInputElement>()
-                    .value()
-                    .parse::<u64>()
-                    .unwrap(),
+        let set_list = set_list.clone();
+        Callback::from(move |musical_id: u64| {
+            set_list.emit(update_item_in_list(&list, id, |item| ListItem {
+                musical_id,
+                custom: None,
                 ..item.clone()
-            });
-            let (new_url, content) = get_url(&list_out);
-            url.set(new_url);
-            let _ = navigator.push_with_query(
-                &Route::Home,
-                &Query {
-                    content: Some(content),
-                    edit,
-                    user: None,
-                },
-            );
-            list.set(list_out);
-        }
+            }))
+        })
+    };
+
+    let set_custom_musical = |id: u64| {
+        let list = list.clone();
+        let set_list = set_list.clone();
+        Callback::from(move |title: String| {
+            set_list.emit(update_item_in_list(&list, id, |item| ListItem {
+                musical_id: 0,
+                custom: Some(CustomMusical {
+                    title: title.clone(),
+                    url: item.custom.as_ref().and_then(|custom| custom.url.clone()),
+                }),
+                ..item.clone()
+            }))
+        })
+    };
+
+    let set_custom_url = |id: u64| {
+        let list = list.clone();
+        let set_list = set_list.clone();
+        Callback::from(move |e: Event| {
+            let url = input_text(e);
+            set_list.emit(update_item_in_list(&list, id, |item| ListItem {
+                custom: item.custom.clone().map(|custom| CustomMusical {
+                    url: url.clone(),
+                    ..custom
+                }),
+                ..item.clone()
+            }))
+        })
+    };
+
+    let add_tag = |id: u64| {
+        let list = list.clone();
+        let set_list = set_list.clone();
+        Callback::from(move |e: Event| {
+            let input = e.target_unchecked_into::<HtmlInputElement>();
+            let mut list_out = (*list).clone();
+            list_out.add_tag(id, &input.value());
+            input.set_value("");
+            set_list.emit(list_out);
+        })
+    };
+
+    let remove_tag = |id: u64, index: u32| {
+        let list = list.clone();
+        update_list_fn!({
+            let mut list_out = (*list).clone();
+            list_out.remove_tag(id, index);
+            list_out
+        })
     };
 
     let update_author = {
-        let url = bookmark_url.clone();
-        let navigator = navigator.clone();
         let list = list.clone();
+        let set_list = set_list.clone();
         Callback::from(move |e: FocusEvent| {
-            let list_out = MusicaList {
-                version: (*list).clone().version,
-                author: e
-                    .target_unchecked_into::<HtmlInputElement>()
-                    .value()
-                    .clone(),
-                items: (*list).clone().items,
-            };
-            let (new_url, content) = get_url(&list_out);
-            url.set(new_url);
-            let _ = navigator.push_with_query(
-                &Route::Home,
-                &Query {
-                    content: Some(content),
-                    edit,
-                    user: None,
-                },
-            );
-            list.set(list_out);
+            set_list.emit(MusicaList {
+                author: e.target_unchecked_into::<HtmlInputElement>().value(),
+                ..(*list).clone()
+            })
+        })
+    };
+
+    let change_rating_scale = {
+        let list = list.clone();
+        let set_list = set_list.clone();
+        Callback::from(move |e: Event| {
+            let max = e.target_unchecked_into::<HtmlInputElement>().value();
+            if let Some(scale) = RatingScale::ALL
+                .into_iter()
+                .find(|scale| scale.max().to_string() == max)
+            {
+                let mut list_out = (*list).clone();
+                list_out.set_rating_scale(scale);
+                set_list.emit(list_out);
+            }
         })
     };
 
     fn get_musical_url(musical_id: u64) -> String {
         format!(
             "https://en.wikipedia.org/wiki/{}",
-            musicals::MUSICALS
+            MUSICALS
                 .iter()
                 .find(|m| m.id == musical_id)
                 .map(|m| m.url.clone())
@@ -438,95 +1300,482 @@ fn home() -> Html {
         )
     }
 
-    let mut i = 0;
+    if let Some(err) = decode_error {
+        let toggle_salvaged = {
+            let show_salvaged = show_salvaged.clone();
+            move |_| show_salvaged.set(!*show_salvaged)
+        };
+        let keep_salvaged = {
+            let salvaged = salvaged.clone();
+            update_list_fn!(salvaged.clone().unwrap_or_else(empty_list))
+        };
+        // keeping the salvaged list saves it over what is stored
+        let stored = salvaged.as_ref().is_some_and(|salvaged| {
+            !get_content_local_storage(&None).is_empty()
+                || !get_content_local_storage(&Some(salvaged.author.clone())).is_empty()
+        });
+        return html! {
+            <>
+            <p>{ "This list could not be read: " }{ err.to_string() }</p>
+            <p>{ "The link was probably cut when it was shared. Nothing was saved." }</p>
+            if let Some(salvaged) = &salvaged {
+                <p>
+                { format!("{} item(s) of {}'s list could be recovered.", salvaged.items.len(), salvaged.author) }
+                { " " }
+                <button onclick={toggle_salvaged} title="show the items which could be read">{
+                    if *show_salvaged { "hide them" } else { "show them" }
+                }</button>
+                </p>
+                if *show_salvaged {
+                    <table class={"center"}>
+                        <tr>
+                            <th>{ "Musical" }</th>
+                            <th>{ "Status" }</th>
+                            <th>{ "Rating" }</th>
+                        </tr>
+                        { for salvaged.items.iter().map(|item| html! {
+                            <tr>
+                                <td>{ item.display_name(&MUSICALS) }</td>
+                                <td>{ format!("{} {}", item.status.icon(), item.status.label()) }</td>
+                                <td>
+                                if item.status == Status::Seen {
+                                    { salvaged.rating_scale.format(item.aggregate_rating()) }
+                                }
+                                </td>
+                            </tr>
+                        })}
+                    </table>
+                    <p>
+                    if stored {
+                        { "Keeping them replaces the list stored in this browser. " }
+                    }
+                    <button onclick={keep_salvaged} title="save the items which could be read">{ "keep them" }</button>
+                    </p>
+                }
+            }
+            <p>
+            if content_from_url {
+                <a href={"/musicalist/"}>{ "back to my list" }</a>
+                { " " }
+            }
+            <a href={clear_all_url()}>{ "New" }</a>
+            </p>
+            </>
+        };
+    }
+
+    let merge_preview = |theirs: &MusicaList| {
+        let merge = merge(&list, theirs, *merge_policy);
+        let name = |id: u64| {
+            merge
+                .list
+                .items
+                .iter()
+                .find(|item| item.id == id)
+                .map(|item| item.display_name(&MUSICALS))
+                .unwrap_or_default()
+        };
+        let state = |(status, rating): (Status, u8)| {
+            format!("{} {}", status.icon(), list.rating_scale.stars(rating))
+        };
+        let apply = {
+            let merged = merged.clone();
+            let list_out = merge.list.clone();
+            let set_list = set_list.clone();
+            move |_| {
+                set_list.emit(list_out.clone());
+                merged.set(None);
+            }
+        };
+        html! {
+            <div class={"preview"}>
+            <p>
+            { format!("Merging {}'s list: {} musical(s) to add, {} conflict(s).",
+                theirs.author, merge.added.len(), merge.conflicts.len()) }
+            </p>
+            if !merge.conflicts.is_empty() {
+                <p>
+                <select onchange={change_rating_policy.clone()} title="rating conflicts">
+                { for RatingPolicy::ALL.iter().map(|policy| html! {
+                    <option value={ policy.label() } selected={ *policy == merge_policy.rating }>
+                        { policy.label() }
+                    </option>
+                })}
+                </select>
+                { " " }
+                <select onchange={change_status_policy.clone()} title="status conflicts">
+                { for StatusPolicy::ALL.iter().map(|policy| html! {
+                    <option value={ policy.label() } selected={ *policy == merge_policy.status }>
+                        { policy.label() }
+                    </option>
+                })}
+                </select>
+                </p>
+                <table class={"center"}>
+                    <tr>
+                        <th>{ "Musical" }</th>
+                        <th>{ "Mine" }</th>
+                        <th>{ "Theirs" }</th>
+                        <th>{ "Merged" }</th>
+                    </tr>
+                    { for merge.conflicts.iter().map(|conflict| {
+                        let merged_item = merge.list.items.iter().find(|item| item.id == conflict.id);
+                        html! {
+                            <tr>
+                                <td>{ name(conflict.id) }</td>
+                                <td>{ state(conflict.ours) }</td>
+                                <td>{ state(conflict.theirs) }</td>
+                                <td>{ merged_item.map(|item| state((item.status, item.rating))).unwrap_or_default() }</td>
+                            </tr>
+                        }
+                    })}
+                </table>
+            }
+            if !merge.added.is_empty() {
+                <p>
+                { "Added: " }
+                { merge.added.iter().map(|id| name(*id)).collect::<Vec<_>>().join(", ") }
+                </p>
+            }
+            <p>
+            <button onclick={apply} title="merge this list into mine">{ "apply" }</button>
+            { " " }
+            <button onclick={cancel_merge.clone()}>{ "cancel" }</button>
+            </p>
+            </div>
+        }
+    };
+
+    let musical_name = |id: u64| {
+        MUSICALS
+            .iter()
+            .find(|m| m.id == id)
+            .map(|m| m.display_name())
+            .unwrap_or_default()
+    };
+    let reason = |reason: &Reason| match reason {
+        Reason::SimilarTo(id) => format!("rated like {} by others", musical_name(*id)),
+        Reason::Composer(composer) => format!("also by {}", composer),
+        Reason::Era(era) => format!("also from the {}s", era),
+    };
+
+    let import_preview = |rows: &Vec<ImportRow>| {
+        html! {
+            <div class={"preview"}>
+            <p>
+            { format!("{} row(s) to import, {} to check.",
+                rows.len(), rows.iter().filter(|row| row.needs_review()).count()) }
+            </p>
+            <table class={"center"}>
+                <tr>
+                    <th>{ "Title" }</th>
+                    <th>{ "Musical" }</th>
+                    <th>{ "Status" }</th>
+                    <th>{ "Rating" }</th>
+                </tr>
+                { for rows.iter().enumerate().map(|(r, row)| html! {
+                    <tr class={ if row.needs_review() { "ambiguous" } else { "" } }>
+                        <td>{ &row.title }</td>
+                        <td>
+                        { match &row.found {
+                            TitleMatch::Exact(id) => html! { musical_name(*id) },
+                            TitleMatch::Ambiguous(candidates) => html! {
+                                <select onchange={choose_import_match(r)} title="musical imported">
+                                { for candidates.iter().map(|id| html! {
+                                    <option value={ id.to_string() } selected={ row.musical_id == Some(*id) }>
+                                        { musical_name(*id) }
+                                    </option>
+                                })}
+                                <option value="" selected={ row.musical_id.is_none() }>{ "not in catalog" }</option>
+                                </select>
+                            },
+                            TitleMatch::NotFound => html! { "not in catalog" },
+                        } }
+                        </td>
+                        <td title={ row.status.label() }>{ row.status.icon() }</td>
+                        <td>{ list.rating_scale.stars(row.rating) }</td>
+                    </tr>
+                })}
+            </table>
+            <p>
+            <button onclick={apply_import.clone()} title="add these rows to my list">{ "import" }</button>
+            { " " }
+            <button onclick={cancel_import.clone()}>{ "cancel" }</button>
+            </p>
+            </div>
+        }
+    };
+
+    let duplicates = list.duplicate_musicals();
+    let shown = view.apply(&list, &MUSICALS);
+    let rated_viewings =
+        |item: &ListItem| item.viewings.iter().any(|viewing| viewing.rating.is_some());
+    let rating_title = |item: &ListItem| {
+        let rating = list.rating_scale.format(item.aggregate_rating());
+        if rated_viewings(item) {
+            format!("{}, average of the rated viewings", rating)
+        } else {
+            rating
+        }
+    };
+    // Musical, Wiki, Status, Rating and Notes, plus the optional ones
+    let columns = if details == Some(true) { 9 } else { 5 } + usize::from(edit == Some(true));
+    let sort_header = |key: SortKey, label: &str| {
+        let arrow = match view.sort {
+            Some(sort) if sort == key && view.descending => " ▼",
+            Some(sort) if sort == key => " ▲",
+            _ => "",
+        };
+        html! {
+            <th class={"sortable"} onclick={sort_by(key)} title="sort">{ label }{ arrow }</th>
+        }
+    };
     html! {
         <>
         if edit == Some(true) {
             { "Musicalist for " }
             <input type="text" value={ (*list).clone().author } onfocusout={update_author}/>
+            { ", rated out of " }
+            <select onchange={change_rating_scale} title="rating scale">
+            { for RatingScale::ALL.iter().map(|scale| html! {
+                <option value={ scale.max().to_string() } selected={ *scale == list.rating_scale }>
+                    { scale.max() }
+                </option>
+            })}
+            </select>
         } else {
             { (*list).clone().author }
             { "'s Musicalist" }
         }
         <br/>
         <br/>
+        <label>
+            <input type="checkbox" checked={ view.unseen_only } onchange={change_unseen_only}/>
+            { " unseen only" }
+        </label>
+        { ", " }
+        <select onchange={change_status_filter} title="show only this status">
+            <option value="" selected={ view.status.is_none() }>{ "any status" }</option>
+            { for Status::ALL.iter().map(|status| html! {
+                <option value={ status.name() } selected={ view.status == Some(*status) }>
+                    { format!("{} {}", status.icon(), status.label()) }
+                </option>
+            })}
+        </select>
+        { ", rating ≥ " }
+        <input class={"position"} type="number" min="0" max={ list.rating_scale.max().to_string() }
+            value={ view.min_rating.unwrap_or(0).to_string() } onchange={change_min_rating}/>
+        if !list.tags.is_empty() {
+            { ", " }
+            <select onchange={change_tag_filter} title="show only this tag">
+                <option value="" selected={ view.tag.is_none() }>{ "any tag" }</option>
+                { for list.tags.iter().map(|tag| html! {
+                    <option value={ tag.clone() } selected={ view.tag.as_ref() == Some(tag) }>
+                        { format!("#{}", tag) }
+                    </option>
+                })}
+            </select>
+        }
+        if shown.len() < list.items.len() {
+            { format!(" ({} of {} shown)", shown.len(), list.items.len()) }
+        }
+        <br/>
+        <br/>
+        <datalist id={"tags"}>
+            { for list.tags.iter().map(|tag| html! { <option value={ tag.clone() }/> }) }
+        </datalist>
         <table class={"center"}>
             <tr>
-                <th>{ "Musical" }</th>
+                { sort_header(SortKey::Name, "Musical") }
                 <th>{ "Wiki" }</th>
-                <th>{ "Viewed" }</th>
-                <th>{ "Rating"}</th>
+                if details == Some(true) {
+                    { sort_header(SortKey::Year, "Year") }
+                    <th>{ "Composer" }</th>
+                    <th>{ "Lyricist" }</th>
+                    <th>{ "Book" }</th>
+                }
+                { sort_header(SortKey::Status, "Status") }
+                { sort_header(SortKey::Rating, "Rating") }
+                <th>{ "Notes" }</th>
                 if  edit == Some(true) {
                     <th>{ "actions" }</th>
                 }
             </tr>
-            { for (*list).clone().items.iter().map(|item| {
-                                                              { i += 1; }
+            { for shown.iter().map(|(i, item)| {
+                let i = *i;
+                let musical = item.musical(&MUSICALS);
                 html! {
-                    <tr>
+                    <>
+                    <tr draggable={ (edit == Some(true) && view.sort.is_none()).to_string() }
+                        ondragstart={drag_start(item.id)}
+                        ondragover={|e: DragEvent| e.prevent_default()}
+                        ondrop={drop_on(i)}
+                        class={ if *dragged == Some(item.id) { "dragged" } else { "" } }>
                         <td>
-                        if i == (*list).items.len() && edit == Some(true) {
-                        <select onchange={change_musical(item.id)}>
-                            { for MUSICALS.iter().map(|m| {
-                                if m.id == item.musical_id {
-                                    html! {
-                                        <option value={ format!("{}", m.id) } selected=true>{ &m.name }</option>
-                                    }
-                                } else {
-                                    html! {
-                                    }
-                                }
-                            })}
-                            { for MUSICALS.iter().map(|m| {
-                                if m.id != item.musical_id {
-                                html! {
-                                    <option value={ format!("{}", m.id) }>{ &m.name }</option>
-                                }
-                                } else {
-                                    html! {
-                                    }
-                                }
-                            })}
-                        </select>
+                        if edit == Some(true) {
+                            <MusicalPicker musical_id={item.musical_id}
+                                custom={item.custom.as_ref().map(|custom| custom.title.clone())}
+                                onselect={change_musical(item.id)} oncustom={set_custom_musical(item.id)}/>
                         } else {
-                            { MUSICALS.iter().find(|m| m.id == item.musical_id).map(|m| m.name.clone()).unwrap_or("".to_string()) }
+                            { item.display_name(&MUSICALS) }
+                        }
+                        if let Some(custom) = &item.custom {
+                            { " " }
+                            <span class={"custom"} title="added by hand, this musical is not in the catalog">
+                                { "not in catalog" }
+                            </span>
+                            if edit == Some(true) {
+                                { " " }
+                                <input type="url" placeholder="url" value={ custom.url.clone().unwrap_or_default() }
+                                    onchange={set_custom_url(item.id)}/>
+                            }
+                        } else if edit == Some(true) && duplicates.contains(&item.musical_id) {
+                            <span title="this musical is listed more than once">{ " ⚠" }</span>
+                        }
+                        <br/>
+                        { for item.tags.iter().filter_map(|index| {
+                            list.tags.get(*index as usize).map(|tag| (*index, tag))
+                        }).map(|(index, tag)| html! {
+                            if edit == Some(true) {
+                                <span class={"tag"}>
+                                    { format!("#{}", tag) }
+                                    <button title="remove tag" onclick={remove_tag(item.id, index)}>{ "×" }</button>
+                                </span>
+                            } else {
+                                <button class={"tag"} title="show only this tag" onclick={filter_tag(tag)}>
+                                    { format!("#{}", tag) }
+                                </button>
+                            }
+                        })}
+                        if edit == Some(true) {
+                            <input class={"tag"} type="text" list="tags" placeholder="＋ tag"
+                                onchange={add_tag(item.id)}/>
                         }
                         </td>
                         <td>
-                        <a href={get_musical_url(item.musical_id)}>{"?"}</a>
+                        if let Some(custom) = &item.custom {
+                            if let Some(url) = &custom.url {
+                                <a href={ url.clone() }>{"?"}</a>
+                            }
+                        } else {
+                            <a href={get_musical_url(item.musical_id)}>{"?"}</a>
+                        }
                         </td>
+                        if details == Some(true) {
+                            <td>{ musical.and_then(|m| m.year).map(|year| year.to_string()).unwrap_or_default() }</td>
+                            <td>{ musical.and_then(|m| m.composer.clone()).unwrap_or_default() }</td>
+                            <td>{ musical.and_then(|m| m.lyricist.clone()).unwrap_or_default() }</td>
+                            <td>{ musical.and_then(|m| m.book_writer.clone()).unwrap_or_default() }</td>
+                        }
                         <td>
                         if edit == Some(true) {
-                            <input type="checkbox" checked={ item.viewed } onchange={change_viewed(item.id)}/>
+                            <select onchange={set_status(item.id)} title="status">
+                            { for Status::ALL.iter().map(|status| html! {
+                                <option value={ status.name() } selected={ *status == item.status }>
+                                    { format!("{} {}", status.icon(), status.label()) }
+                                </option>
+                            })}
+                            </select>
+                        } else {
+                            <span title={ item.status.label() }>{ item.status.icon() }</span>
+                        }
+                        </td>
+                        <td title={ rating_title(item) }>
+                        if edit == Some(true) && !rated_viewings(item) {
+                            <RatingInput scale={list.rating_scale} rating={item.rating} onchange={set_rating(item.id)}/>
                         } else {
-                            { if item.viewed { "👁" } else { "" } }
+                            { list.rating_scale.stars(item.aggregate_rating()) }
+                        }
+                        if item.viewings.len() > 1 {
+                            { format!(" ×{}", item.viewings.len()) }
+                        }
+                        </td>
+                        <td>
+                        if edit == Some(true) || item.has_notes() {
+                            <button onclick={toggle_details(item.id)} title={
+                                if expanded.contains(&item.id) { "hide notes" } else { "show notes" }
+                            }>{ if item.has_notes() { "📝" } else { "＋" } }</button>
                         }
                         </td>
-                        <td>{ item.rating }</td>
                         if edit == Some(true) {
                             <td>
-                                <button title="increase rating" onclick={update_rating(item.id, 1)}>{ "➕" } </button>
-                                { " " }
-                                <button title="decrease rating" onclick={update_rating(item.id, -1)}>{ "➖" } </button>
+                                <button title="move to top" onclick={move_item(item.id, 0)}>{ "⤒" } </button>
+                                <button title="move up" onclick={move_item(item.id, i.saturating_sub(1))}>{ "⬆" } </button>
+                                <button title="move down" onclick={move_item(item.id, i + 1)}>{ "⬇" } </button>
+                                <button title="move to bottom" onclick={move_item(item.id, usize::MAX)}>{ "⤓" } </button>
                                 { " " }
-                                <button title="move up" onclick={move_item(i - 1, -1)}>{ "⬆" } </button>
-                                { " " }
-                                <button title="move down" onclick={move_item(i - 1, 1)}>{ "⬇" } </button>
+                                <input class={"position"} type="number" min="1" max={ list.items.len().to_string() }
+                                    value={ (i + 1).to_string() } title="move to position"
+                                    onchange={move_to_position(item.id)}/>
                                 { " " }
                                 <button title="remove entry" onclick={delete(item.id)}>{ "🗑 " } </button>
                                 </td>
                         }
                     </tr>
+                    if expanded.contains(&item.id) {
+                        <tr class={"details"}>
+                            <td colspan={ columns.to_string() }>
+                            <table class={"viewings"}>
+                            { for item.viewings.iter().enumerate().map(|(v, viewing)| html! {
+                                <tr>
+                                if edit == Some(true) {
+                                    <td><input type="date" value={ viewing.date.clone().unwrap_or_default() }
+                                        onchange={set_viewing_field(item.id, v, |v| &mut v.date)}/></td>
+                                    <td><input type="text" placeholder="theatre" value={ viewing.theatre.clone().unwrap_or_default() }
+                                        onchange={set_viewing_field(item.id, v, |v| &mut v.theatre)}/></td>
+                                    <td><input type="text" placeholder="city" value={ viewing.city.clone().unwrap_or_default() }
+                                        onchange={set_viewing_field(item.id, v, |v| &mut v.city)}/></td>
+                                    <td><input type="text" placeholder="cast" value={ viewing.cast.clone().unwrap_or_default() }
+                                        onchange={set_viewing_field(item.id, v, |v| &mut v.cast)}/></td>
+                                    <td><RatingInput scale={list.rating_scale} rating={viewing.rating.unwrap_or(0)}
+                                        onchange={set_viewing_rating(item.id, v)}/></td>
+                                    <td><button title="remove viewing" onclick={delete_viewing(item.id, v)}>{ "🗑 " }</button></td>
+                                } else {
+                                    <td>{ [&viewing.date, &viewing.theatre, &viewing.city]
+                                        .into_iter()
+                                        .flatten()
+                                        .cloned()
+                                        .collect::<Vec<_>>()
+                                        .join(", ") }</td>
+                                    <td>{ viewing.cast.clone().map(|cast| format!("Cast: {}", cast)).unwrap_or_default() }</td>
+                                    <td>{ viewing.rating.map(|rating| list.rating_scale.stars(rating)).unwrap_or_default() }</td>
+                                }
+                                </tr>
+                            })}
+                            </table>
+                            if edit == Some(true) {
+                                <button onclick={add_viewing(item.id)} title="add a viewing">{ "＋ viewing" }</button>
+                                <br/>
+                                <textarea placeholder="review" value={ item.review.clone().unwrap_or_default() }
+                                    onchange={set_review(item.id)}/>
+                            } else if let Some(review) = &item.review {
+                                <p class={"review"}>{ review }</p>
+                            }
+                            </td>
+                        </tr>
+                    }
+                    </>
                 }
             })}
         </table>
+        if !recommendations.is_empty() {
+            <p>
+            { "You might like: " }
+            { for recommendations.iter().map(|recommendation| html! {
+                <span class={"recommendation"} title={ reason(&recommendation.reason) }>
+                    { musical_name(recommendation.musical_id) }
+                    <button title="add to my list" onclick={add_musical(recommendation.musical_id)}>{ "➕" }</button>
+                </span>
+            })}
+            </p>
+        }
         <p>
         if edit == Some(true) {
-            <button onclick={add_musical} title="add musical">{ "➕" } </button>
+            <button onclick={add_musical(1)} title="add musical">{ "➕" } </button>
             { " " }
-            <button onclick={go(-1)} title="undo">{ "🔙" } </button>
+            <button onclick={undo} title="undo" disabled={!history.can_undo()}>{ "🔙" } </button>
             { " " }
-            <button onclick={go(1)} title="redo">{ "⏩" } </button>
+            <button onclick={redo} title="redo" disabled={!history.can_redo()}>{ "⏩" } </button>
             { " " }
         }
         <button onclick={change_edit} title={
@@ -542,13 +1791,53 @@ fn home() -> Html {
                 "🖊 "
             }
         } </button>
+        { " " }
+        <button onclick={change_details} title={
+            if details == Some(true) {
+                "hide year, composer, lyricist and book writer"
+            } else {
+                "show year, composer, lyricist and book writer"
+            }
+        }>{ "ℹ " }</button>
         </p>
+        if edit == Some(true) {
+            <p>
+            { "Merge a list into mine: " }
+            <input type="text" placeholder="sharing url" onchange={preview_merge}/>
+            </p>
+            <p>
+            { "Import a spreadsheet: " }
+            <input type="file" accept=".csv,.tsv,.txt,text/csv" onchange={import_file}/>
+            { " or " }
+            <textarea placeholder="paste its rows: title, rating, seen" onchange={import_pasted}/>
+            </p>
+        }
+        if let Some(Err(err)) = &*imported {
+            <p>
+            { "This spreadsheet could not be read: " }{ err }
+            { " " }
+            <button onclick={cancel_import.clone()}>{ "dismiss" }</button>
+            </p>
+        }
+        if let Some(Ok(rows)) = &*imported {
+            { import_preview(rows) }
+        }
+        if let Some(Err(err)) = &*merged {
+            <p>
+            { "This list could not be read: " }{ err }
+            { " " }
+            <button onclick={cancel_merge.clone()}>{ "dismiss" }</button>
+            </p>
+        }
+        if let Some(Ok(theirs)) = &*merged {
+            { merge_preview(theirs) }
+        }
         <p>
         <a href={clear_all_url()}>{ "New" }</a>
         { " " }
         <a href={"https://github.com/yazgoo/musicalist"}>{ "about" }</a>
         { " " }
-        <a href={ get_url(&list).clone().0.replace("edit=true", "edit=false") }
+        <a href={ format!("{}{}", get_url(&list).0, view.url_params()) }
         title={"Right click + copy link adress to get url"}>{ "sharing url" }</a>
         </p>
         { "Users:" }
@@ -557,12 +1846,18 @@ fn home() -> Html {
         { for get_users().items.iter().map(|user| {
             html! {
                 <tr>
+                <td><input type="checkbox" title="compare" checked={ compared.contains(user) }
+                    onchange={toggle_compared(user.clone())}/></td>
                 <td><a href={ format!("/musicalist?user={}", user) }>{ user }</a></td>
                 <td><button title="remove user list" onclick={delete_user(user.clone())}>{ "🗑 " }</button></td>
                 </tr>
             }
         })}
         </table>
+        <button onclick={compare_users} disabled={ compared.len() < 2 }
+            title="compare the lists of the checked users">{ "compare" }</button>
+        { " " }
+        <button onclick={show_group} title="leaderboard of the lists of every user">{ "group" }</button>
         </>
     }
 }
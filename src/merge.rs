@@ -0,0 +1,327 @@
+//! Merge of another list, e.g. from a friend's sharing url, into a list.
+//!
+//! Musicals are matched as in comparisons, see [`MusicalKey`]. The items of
+//! the list merged into keep their order and ids; the musicals it misses are
+//! added after them, in the order of the other list.
+use crate::compare::MusicalKey;
+use crate::model::{ListItem, MusicaList, Status, Viewing};
+use std::collections::HashMap;
+
+/// Which rating to keep for a musical rated differently by both lists.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RatingPolicy {
+    #[default]
+    Ours,
+    Theirs,
+    Highest,
+    Average,
+}
+
+impl RatingPolicy {
+    pub const ALL: [RatingPolicy; 4] = [
+        RatingPolicy::Ours,
+        RatingPolicy::Theirs,
+        RatingPolicy::Highest,
+        RatingPolicy::Average,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            RatingPolicy::Ours => "keep my rating",
+            RatingPolicy::Theirs => "take their rating",
+            RatingPolicy::Highest => "keep the highest rating",
+            RatingPolicy::Average => "average the ratings",
+        }
+    }
+
+    fn resolve(self, ours: u8, theirs: u8) -> u8 {
+        match self {
+            RatingPolicy::Ours => ours,
+            RatingPolicy::Theirs => theirs,
+            RatingPolicy::Highest => ours.max(theirs),
+            RatingPolicy::Average => ((ours as u16 + theirs as u16).div_ceil(2)) as u8,
+        }
+    }
+}
+
+/// Which status to keep for a musical at a different status in both lists.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum StatusPolicy {
+    #[default]
+    Ours,
+    Theirs,
+    /// Seen, else abandoned, else booked.
+    Furthest,
+}
+
+impl StatusPolicy {
+    pub const ALL: [StatusPolicy; 3] = [
+        StatusPolicy::Ours,
+        StatusPolicy::Theirs,
+        StatusPolicy::Furthest,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            StatusPolicy::Ours => "keep my status",
+            StatusPolicy::Theirs => "take their status",
+            StatusPolicy::Furthest => "keep the furthest status",
+        }
+    }
+
+    fn resolve(self, ours: Status, theirs: Status) -> Status {
+        let rank = |status| match status {
+            Status::WantToSee => 0,
+            Status::Booked => 1,
+            Status::Abandoned => 2,
+            Status::Seen => 3,
+        };
+        match self {
+            StatusPolicy::Ours => ours,
+            StatusPolicy::Theirs => theirs,
+            StatusPolicy::Furthest if rank(theirs) > rank(ours) => theirs,
+            StatusPolicy::Furthest => ours,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct MergePolicy {
+    pub rating: RatingPolicy,
+    pub status: StatusPolicy,
+}
+
+/// A musical whose status or rating differ in both lists, the ratings on
+/// the scale of the list merged into.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MergeConflict {
+    /// Id of the item in the merged list.
+    pub id: u64,
+    pub ours: (Status, u8),
+    pub theirs: (Status, u8),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Merge {
+    pub list: MusicaList,
+    /// Ids of the items added from the other list.
+    pub added: Vec<u64>,
+    pub conflicts: Vec<MergeConflict>,
+}
+
+/// `theirs` merged into `ours`, resolving conflicts with `policy`. Viewings
+/// and tags are united, reviews are kept unless ours has none.
+pub fn merge(ours: &MusicaList, theirs: &MusicaList, policy: MergePolicy) -> Merge {
+    let mut list = ours.clone();
+    let mut added = vec![];
+    let mut conflicts = vec![];
+    let mut index_of: HashMap<MusicalKey, usize> = HashMap::new();
+    for (i, item) in list.items.iter().enumerate() {
+        index_of.entry(MusicalKey::of(item)).or_insert(i);
+    }
+    let convert = |rating| theirs.rating_scale.convert(rating, ours.rating_scale);
+    for their_item in theirs.items.iter() {
+        let viewings: Vec<Viewing> = their_item
+            .viewings
+            .iter()
+            .map(|viewing| Viewing {
+                rating: viewing.rating.map(convert),
+                ..viewing.clone()
+            })
+            .collect();
+        let rating = convert(their_item.rating);
+        let id = match index_of.get(&MusicalKey::of(their_item)) {
+            Some(&i) => {
+                let item = &mut list.items[i];
+                if item.status != their_item.status || item.rating != rating {
+                    conflicts.push(MergeConflict {
+                        id: item.id,
+                        ours: (item.status, item.rating),
+                        theirs: (their_item.status, rating),
+                    });
+                    item.status = policy.status.resolve(item.status, their_item.status);
+                    item.rating = policy.rating.resolve(item.rating, rating);
+                }
+                for viewing in viewings {
+                    if !item.viewings.contains(&viewing) {
+                        item.viewings.push(viewing);
+                    }
+                }
+                if item.review.is_none() {
+                    item.review = their_item.review.clone();
+                }
+                item.id
+            }
+            None => {
+                let id = list.next_item_id();
+                index_of.insert(MusicalKey::of(their_item), list.items.len());
+                list.items.push(ListItem {
+                    id,
+                    rating,
+                    viewings,
+                    tags: vec![],
+                    ..their_item.clone()
+                });
+                added.push(id);
+                id
+            }
+        };
+        for tag in theirs.item_tags(their_item) {
+            list.add_tag(id, tag);
+        }
+    }
+    Merge {
+        list,
+        added,
+        conflicts,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::{CustomMusical, RatingScale};
+
+    fn item(id: u64, musical_id: u64, status: Status, rating: u8) -> ListItem {
+        ListItem {
+            id,
+            musical_id,
+            status,
+            rating,
+            ..Default::default()
+        }
+    }
+
+    fn list(rating_scale: RatingScale, items: Vec<ListItem>) -> MusicaList {
+        MusicaList {
+            rating_scale,
+            items,
+            ..Default::default()
+        }
+    }
+
+    fn ours() -> MusicaList {
+        list(
+            RatingScale::Ten,
+            vec![
+                item(1, 10, Status::Seen, 8),
+                item(3, 20, Status::WantToSee, 0),
+            ],
+        )
+    }
+
+    fn theirs() -> MusicaList {
+        list(
+            RatingScale::Hundred,
+            vec![
+                item(1, 30, Status::Booked, 0),
+                item(2, 20, Status::Seen, 60),
+                item(3, 10, Status::Seen, 80),
+                item(4, 40, Status::Seen, 100),
+            ],
+        )
+    }
+
+    fn musical_ids(list: &MusicaList) -> Vec<u64> {
+        list.items.iter().map(|item| item.musical_id).collect()
+    }
+
+    #[test]
+    fn unites_musicals_keeping_our_order() {
+        let merged = merge(&ours(), &theirs(), MergePolicy::default());
+        assert_eq!(musical_ids(&merged.list), vec![10, 20, 30, 40]);
+        let ids: Vec<u64> = merged.list.items.iter().map(|item| item.id).collect();
+        assert_eq!(ids, vec![1, 3, 4, 5]);
+        assert_eq!(merged.added, vec![4, 5]);
+        // their ratings are moved to our scale
+        assert_eq!(merged.list.items[3].rating, 10);
+    }
+
+    #[test]
+    fn reports_conflicts() {
+        let merged = merge(&ours(), &theirs(), MergePolicy::default());
+        // the same rating once on the same scale is no conflict
+        assert_eq!(
+            merged.conflicts,
+            vec![MergeConflict {
+                id: 3,
+                ours: (Status::WantToSee, 0),
+                theirs: (Status::Seen, 6),
+            }]
+        );
+        // the default policy keeps what we had
+        assert_eq!(merged.list.items[..2], ours().items[..]);
+    }
+
+    #[test]
+    fn resolves_conflicts_with_the_policy() {
+        let policy = MergePolicy {
+            rating: RatingPolicy::Highest,
+            status: StatusPolicy::Furthest,
+        };
+        let merged = merge(&ours(), &theirs(), policy);
+        assert_eq!(merged.list.items[1].status, Status::Seen);
+        assert_eq!(merged.list.items[1].rating, 6);
+        let theirs = merge(
+            &ours(),
+            &theirs(),
+            MergePolicy {
+                rating: RatingPolicy::Theirs,
+                status: StatusPolicy::Theirs,
+            },
+        );
+        assert_eq!(theirs.list.items[1].status, Status::Seen);
+        assert_eq!(RatingPolicy::Average.resolve(7, 10), 9);
+        assert_eq!(
+            StatusPolicy::Furthest.resolve(Status::Seen, Status::Booked),
+            Status::Seen
+        );
+    }
+
+    #[test]
+    fn merging_a_list_into_itself_changes_nothing() {
+        let merged = merge(&theirs(), &theirs(), MergePolicy::default());
+        assert_eq!(merged.list, theirs());
+        assert!(merged.added.is_empty());
+        assert!(merged.conflicts.is_empty());
+    }
+
+    #[test]
+    fn unites_viewings_tags_and_reviews() {
+        let mut ours = ours();
+        ours.add_tag(1, "Sondheim");
+        let mut theirs = theirs();
+        theirs.add_tag(3, "sondheim");
+        theirs.add_tag(3, "saw with Ana");
+        theirs.items[2].review = Some("Loved it".to_string());
+        theirs.items[2].viewings = vec![Viewing {
+            city: Some("London".to_string()),
+            rating: Some(80),
+            ..Default::default()
+        }];
+        let merged = merge(&ours, &theirs, MergePolicy::default()).list;
+        assert_eq!(merged.tags, vec!["Sondheim", "saw with Ana"]);
+        assert_eq!(merged.items[0].tags, vec![0, 1]);
+        assert_eq!(merged.items[0].review.as_deref(), Some("Loved it"));
+        assert_eq!(merged.items[0].viewings[0].rating, Some(8));
+    }
+
+    #[test]
+    fn matches_custom_musicals_by_title() {
+        let custom = |id, title: &str| ListItem {
+            custom: Some(CustomMusical {
+                title: title.to_string(),
+                url: None,
+            }),
+            ..item(id, 0, Status::Seen, 5)
+        };
+        let ours = list(RatingScale::Ten, vec![custom(1, "Fringe Show")]);
+        let theirs = list(
+            RatingScale::Ten,
+            vec![custom(1, "fringe show"), custom(2, "Other Show")],
+        );
+        let merged = merge(&ours, &theirs, MergePolicy::default());
+        assert_eq!(merged.list.items.len(), 2);
+        assert_eq!(merged.added, vec![2]);
+    }
+}
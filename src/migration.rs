@@ -0,0 +1,694 @@
+//! Upgrade of lists serialized by past versions into the current [`MusicaList`].
+//!
+//! Every version that was ever shared has a frozen copy of its structs in a
+//! `vN` module below. Those modules must never change: instead, bump
+//! `CURRENT_VERSION`, freeze the new layout in a new module, add it to
+//! `Versioned` and add a `from_vN` step upgrading the previous version into
+//! it: `upgrade` takes a list from any version through every step up to the
+//! current one.
+use crate::codec::{DecodeError, CURRENT_VERSION};
+use crate::model::{CustomMusical, ListItem, MusicaList, RatingScale, Status, Viewing};
+
+pub mod v1 {
+    #[derive(Debug, serde::Deserialize, Clone)]
+    pub struct ListItem {
+        pub id: u64,
+        pub musical_id: u64,
+        pub viewed: bool,
+        pub rating: u8,
+    }
+
+    #[derive(Debug, serde::Deserialize, Clone)]
+    pub struct MusicaList {
+        pub version: u8,
+        pub author: String,
+        pub items: Vec<ListItem>,
+    }
+}
+
+pub mod v2 {
+    pub use super::v1::ListItem;
+
+    #[derive(Debug, serde::Deserialize, Clone)]
+    pub struct MusicaList {
+        pub version: u8,
+        pub author: String,
+        /// Largest rating: 5, 10 or 100.
+        pub rating_scale: u8,
+        pub items: Vec<ListItem>,
+    }
+}
+
+pub mod v3 {
+    #[derive(Debug, serde::Deserialize, Clone)]
+    pub struct ItemDetails {
+        pub date: Option<String>,
+        pub theatre: Option<String>,
+        pub city: Option<String>,
+        pub cast: Option<String>,
+        pub review: Option<String>,
+    }
+
+    #[derive(Debug, serde::Deserialize, Clone)]
+    pub struct ListItem {
+        pub id: u64,
+        pub musical_id: u64,
+        pub viewed: bool,
+        pub rating: u8,
+        pub details: Option<ItemDetails>,
+    }
+
+    #[derive(Debug, serde::Deserialize, Clone)]
+    pub struct MusicaList {
+        pub version: u8,
+        pub author: String,
+        /// Largest rating: 5, 10 or 100.
+        pub rating_scale: u8,
+        pub items: Vec<ListItem>,
+    }
+}
+
+pub mod v4 {
+    #[derive(Debug, serde::Deserialize, Clone)]
+    pub struct Viewing {
+        pub date: Option<String>,
+        pub theatre: Option<String>,
+        pub city: Option<String>,
+        pub cast: Option<String>,
+        pub rating: Option<u8>,
+    }
+
+    #[derive(Debug, serde::Deserialize, Clone)]
+    pub struct ListItem {
+        pub id: u64,
+        pub musical_id: u64,
+        pub viewed: bool,
+        pub rating: u8,
+        pub review: Option<String>,
+        pub viewings: Vec<Viewing>,
+    }
+
+    #[derive(Debug, serde::Deserialize, Clone)]
+    pub struct MusicaList {
+        pub version: u8,
+        pub author: String,
+        /// Largest rating: 5, 10 or 100.
+        pub rating_scale: u8,
+        pub items: Vec<ListItem>,
+    }
+}
+
+pub mod v5 {
+    pub use super::v4::Viewing;
+
+    #[derive(Debug, serde::Deserialize, Clone)]
+    pub struct ListItem {
+        pub id: u64,
+        pub musical_id: u64,
+        /// Want to see, booked, seen or abandoned, from 0.
+        pub status: u8,
+        pub rating: u8,
+        pub review: Option<String>,
+        pub viewings: Vec<Viewing>,
+    }
+
+    #[derive(Debug, serde::Deserialize, Clone)]
+    pub struct MusicaList {
+        pub version: u8,
+        pub author: String,
+        /// Largest rating: 5, 10 or 100.
+        pub rating_scale: u8,
+        pub items: Vec<ListItem>,
+    }
+}
+
+pub mod v6 {
+    pub use super::v4::Viewing;
+
+    #[derive(Debug, serde::Deserialize, Clone)]
+    pub struct CustomMusical {
+        pub title: String,
+        pub url: Option<String>,
+    }
+
+    #[derive(Debug, serde::Deserialize, Clone)]
+    pub struct ListItem {
+        pub id: u64,
+        pub musical_id: u64,
+        /// Want to see, booked, seen or abandoned, from 0.
+        pub status: u8,
+        pub rating: u8,
+        pub review: Option<String>,
+        pub viewings: Vec<Viewing>,
+        /// Indices in the tags of the list.
+        pub tags: Vec<u32>,
+        pub custom: Option<CustomMusical>,
+    }
+
+    #[derive(Debug, serde::Deserialize, Clone)]
+    pub struct MusicaList {
+        pub version: u8,
+        pub author: String,
+        /// Largest rating: 5, 10 or 100.
+        pub rating_scale: u8,
+        pub tags: Vec<String>,
+        pub items: Vec<ListItem>,
+    }
+}
+
+/// A list as serialized by one of the list formats.
+enum Versioned {
+    V1(v1::MusicaList),
+    V2(v2::MusicaList),
+    V3(v3::MusicaList),
+    V4(v4::MusicaList),
+    V5(v5::MusicaList),
+    V6(v6::MusicaList),
+}
+
+/// Upgrade `list` one version at a time into the current list.
+fn upgrade(mut list: Versioned) -> MusicaList {
+    loop {
+        list = match list {
+            Versioned::V1(list) => Versioned::V2(from_v1(list)),
+            Versioned::V2(list) => Versioned::V3(from_v2(list)),
+            Versioned::V3(list) => Versioned::V4(from_v3(list)),
+            Versioned::V4(list) => Versioned::V5(from_v4(list)),
+            Versioned::V5(list) => Versioned::V6(from_v5(list)),
+            Versioned::V6(list) => return from_v6(list),
+        }
+    }
+}
+
+/// Decode `bytes`, serialized by list format `version`, into the current list.
+pub fn migrate(version: u8, bytes: &[u8]) -> Result<MusicaList, DecodeError> {
+    let list = match version {
+        1 => Versioned::V1(rmp_serde::from_slice(bytes)?),
+        2 => Versioned::V2(rmp_serde::from_slice(bytes)?),
+        3 => Versioned::V3(rmp_serde::from_slice(bytes)?),
+        4 => Versioned::V4(rmp_serde::from_slice(bytes)?),
+        5 => Versioned::V5(rmp_serde::from_slice(bytes)?),
+        6 => Versioned::V6(rmp_serde::from_slice(bytes)?),
+        version => return Err(DecodeError::UnsupportedVersion(version)),
+    };
+    Ok(upgrade(list))
+}
+
+/// Read as much of a damaged (e.g. truncated) list as possible: its header
+/// and every item up to the first one that can't be decoded.
+pub fn salvage(bytes: &[u8]) -> Option<MusicaList> {
+    let mut rd = bytes;
+    rmp::decode::read_array_len(&mut rd).ok()?;
+    let version: u8 = rmp_serde::from_read(&mut rd).ok()?;
+    let author = rmp_serde::from_read(&mut rd).ok()?;
+    let list = match version {
+        1 => Versioned::V1(v1::MusicaList {
+            version,
+            author,
+            items: salvage_items(&mut rd)?,
+        }),
+        2 => Versioned::V2(v2::MusicaList {
+            version,
+            author,
+            rating_scale: rmp_serde::from_read(&mut rd).ok()?,
+            items: salvage_items(&mut rd)?,
+        }),
+        3 => Versioned::V3(v3::MusicaList {
+            version,
+            author,
+            rating_scale: rmp_serde::from_read(&mut rd).ok()?,
+            items: salvage_items(&mut rd)?,
+        }),
+        4 => Versioned::V4(v4::MusicaList {
+            version,
+            author,
+            rating_scale: rmp_serde::from_read(&mut rd).ok()?,
+            items: salvage_items(&mut rd)?,
+        }),
+        5 => Versioned::V5(v5::MusicaList {
+            version,
+            author,
+            rating_scale: rmp_serde::from_read(&mut rd).ok()?,
+            items: salvage_items(&mut rd)?,
+        }),
+        6 => Versioned::V6(v6::MusicaList {
+            version,
+            author,
+            rating_scale: rmp_serde::from_read(&mut rd).ok()?,
+            tags: rmp_serde::from_read(&mut rd).ok()?,
+            items: salvage_items(&mut rd)?,
+        }),
+        _ => return None,
+    };
+    Some(upgrade(list))
+}
+
+fn salvage_items<T: serde::de::DeserializeOwned>(rd: &mut &[u8]) -> Option<Vec<T>> {
+    let len = rmp::decode::read_array_len(&mut *rd).ok()?;
+    Some(
+        (0..len)
+            .map_while(|_| rmp_serde::from_read(&mut *rd).ok())
+            .collect(),
+    )
+}
+
+/// Ratings went from 0 to 10 before lists had a scale.
+fn from_v1(list: v1::MusicaList) -> v2::MusicaList {
+    v2::MusicaList {
+        version: 2,
+        author: list.author,
+        rating_scale: 10,
+        items: list.items,
+    }
+}
+
+fn from_v2(list: v2::MusicaList) -> v3::MusicaList {
+    v3::MusicaList {
+        version: 3,
+        author: list.author,
+        rating_scale: list.rating_scale,
+        items: list
+            .items
+            .into_iter()
+            .map(|item| v3::ListItem {
+                id: item.id,
+                musical_id: item.musical_id,
+                viewed: item.viewed,
+                rating: item.rating,
+                details: None,
+            })
+            .collect(),
+    }
+}
+
+/// The date and venue of version 3 become the first viewing.
+fn from_v3(list: v3::MusicaList) -> v4::MusicaList {
+    v4::MusicaList {
+        version: 4,
+        author: list.author,
+        rating_scale: list.rating_scale,
+        items: list
+            .items
+            .into_iter()
+            .map(|item| {
+                let (review, viewings) = match item.details {
+                    Some(details) => {
+                        let viewing = v4::Viewing {
+                            date: details.date,
+                            theatre: details.theatre,
+                            city: details.city,
+                            cast: details.cast,
+                            rating: None,
+                        };
+                        let seen = viewing.date.is_some()
+                            || viewing.theatre.is_some()
+                            || viewing.city.is_some()
+                            || viewing.cast.is_some();
+                        (details.review, if seen { vec![viewing] } else { vec![] })
+                    }
+                    None => (None, vec![]),
+                };
+                v4::ListItem {
+                    id: item.id,
+                    musical_id: item.musical_id,
+                    viewed: item.viewed,
+                    rating: item.rating,
+                    review,
+                    viewings,
+                }
+            })
+            .collect(),
+    }
+}
+
+/// Viewed or rated items are seen, the others were wanted: the viewed box
+/// was often left unticked on rated musicals.
+fn from_v4(list: v4::MusicaList) -> v5::MusicaList {
+    v5::MusicaList {
+        version: 5,
+        author: list.author,
+        rating_scale: list.rating_scale,
+        items: list
+            .items
+            .into_iter()
+            .map(|item| v5::ListItem {
+                id: item.id,
+                musical_id: item.musical_id,
+                status: if item.viewed || item.rating > 0 {
+                    Status::Seen
+                } else {
+                    Status::WantToSee
+                }
+                .into(),
+                rating: item.rating,
+                review: item.review,
+                viewings: item.viewings,
+            })
+            .collect(),
+    }
+}
+
+fn from_v5(list: v5::MusicaList) -> v6::MusicaList {
+    v6::MusicaList {
+        version: 6,
+        author: list.author,
+        rating_scale: list.rating_scale,
+        tags: vec![],
+        items: list
+            .items
+            .into_iter()
+            .map(|item| v6::ListItem {
+                id: item.id,
+                musical_id: item.musical_id,
+                status: item.status,
+                rating: item.rating,
+                review: item.review,
+                viewings: item.viewings,
+                tags: vec![],
+                custom: None,
+            })
+            .collect(),
+    }
+}
+
+/// Tags of items which are not in the tags of the list are dropped.
+fn from_v6(list: v6::MusicaList) -> MusicaList {
+    let rating_scale = RatingScale::try_from(list.rating_scale).unwrap_or_default();
+    let clamp = |rating: u8| rating.min(rating_scale.max());
+    let tag_count = list.tags.len();
+    MusicaList {
+        version: CURRENT_VERSION,
+        author: list.author,
+        rating_scale,
+        tags: list.tags,
+        items: list
+            .items
+            .into_iter()
+            .map(|item| ListItem {
+                id: item.id,
+                musical_id: item.musical_id,
+                status: Status::try_from(item.status).unwrap_or_default(),
+                rating: clamp(item.rating),
+                review: item.review,
+                viewings: item
+                    .viewings
+                    .into_iter()
+                    .map(|viewing| Viewing {
+                        date: viewing.date,
+                        theatre: viewing.theatre,
+                        city: viewing.city,
+                        cast: viewing.cast,
+                        rating: viewing.rating.map(clamp),
+                    })
+                    .collect(),
+                tags: item
+                    .tags
+                    .into_iter()
+                    .filter(|tag| (*tag as usize) < tag_count)
+                    .collect(),
+                custom: item.custom.map(|custom| CustomMusical {
+                    title: custom.title,
+                    url: custom.url,
+                }),
+            })
+            .collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::codec::{decode, DecodeError, CURRENT_VERSION};
+    use crate::model::{ListItem, RatingScale, Status, Viewing};
+
+    /// Sharing url content produced by version 1 (the example list from the README).
+    const V1_FIXTURE: &str = "kwGmWWF6Z29v3AAQlAHNAjXCCpQCzQOlwgmUA80CcsIKlATNBDbCCpQFzQRbwgeUBs0BdsIHlAfNAkHCCJQIzQMGwgeUCc0CL8IKlArMz8IIlAvNA23CCZQMzQRkwgiUDc0B/sIHlA7NAdDCBpQPzQL5wgiUEM0ECcIJ";
+
+    #[test]
+    fn migrates_v1() {
+        let list = decode(V1_FIXTURE).unwrap();
+        assert_eq!(list.version, CURRENT_VERSION);
+        assert_eq!(list.author, "Yazgoo");
+        assert_eq!(list.rating_scale, RatingScale::Ten);
+        assert_eq!(list.items.len(), 16);
+        assert_eq!(
+            list.items[0],
+            ListItem {
+                id: 1,
+                musical_id: 565,
+                status: Status::Seen,
+                rating: 10,
+                review: None,
+                viewings: vec![],
+                tags: vec![],
+                custom: None,
+            }
+        );
+        assert_eq!(
+            list.items[15],
+            ListItem {
+                id: 16,
+                musical_id: 1033,
+                status: Status::Seen,
+                rating: 9,
+                review: None,
+                viewings: vec![],
+                tags: vec![],
+                custom: None,
+            }
+        );
+        // none was ticked viewed, but all were rated
+        assert!(list.items.iter().all(|item| item.status == Status::Seen));
+        let ratings: Vec<u8> = list.items.iter().map(|item| item.rating).collect();
+        assert_eq!(
+            ratings,
+            vec![10, 9, 10, 10, 7, 7, 8, 7, 10, 8, 9, 8, 7, 6, 8, 9]
+        );
+    }
+
+    #[test]
+    fn migrates_v2() {
+        let bytes = rmp_serde::to_vec(&(2u8, "me", 100u8, vec![(1u64, 5u64, true, 75u8)])).unwrap();
+        let list = super::migrate(2, &bytes).unwrap();
+        assert_eq!(list.rating_scale, RatingScale::Hundred);
+        assert_eq!(
+            list.items,
+            vec![ListItem {
+                id: 1,
+                musical_id: 5,
+                status: Status::Seen,
+                rating: 75,
+                review: None,
+                viewings: vec![],
+                tags: vec![],
+                custom: None,
+            }]
+        );
+    }
+
+    #[test]
+    fn migrates_v3_details_to_a_viewing() {
+        let details = (
+            Some("2023-05-14"),
+            Some("Sondheim Theatre"),
+            None::<&str>,
+            None::<&str>,
+            Some("Still great"),
+        );
+        let no_venue = (
+            None::<&str>,
+            None::<&str>,
+            None::<&str>,
+            None::<&str>,
+            Some("Meh"),
+        );
+        let bytes = rmp_serde::to_vec(&(
+            3u8,
+            "me",
+            10u8,
+            vec![
+                (1u64, 5u64, true, 8u8, Some(details)),
+                (2u64, 6u64, true, 3u8, Some(no_venue)),
+                (3u64, 7u64, false, 0u8, None),
+            ],
+        ))
+        .unwrap();
+        let list = super::migrate(3, &bytes).unwrap();
+        assert_eq!(list.items[0].review.as_deref(), Some("Still great"));
+        assert_eq!(
+            list.items[0].viewings,
+            vec![Viewing {
+                date: Some("2023-05-14".to_string()),
+                theatre: Some("Sondheim Theatre".to_string()),
+                ..Default::default()
+            }]
+        );
+        assert_eq!(list.items[0].aggregate_rating(), 8);
+        assert_eq!(list.items[1].review.as_deref(), Some("Meh"));
+        assert!(list.items[1].viewings.is_empty());
+        assert!(!list.items[2].has_notes());
+    }
+
+    #[test]
+    fn migrates_v4() {
+        let viewing = (
+            Some("2024-01-20"),
+            None::<&str>,
+            Some("London"),
+            None::<&str>,
+            Some(9u8),
+        );
+        let bytes = rmp_serde::to_vec(&(
+            4u8,
+            "me",
+            10u8,
+            vec![
+                (1u64, 5u64, true, 7u8, Some("Twice!"), vec![viewing]),
+                (2u64, 6u64, false, 0u8, None, vec![]),
+            ],
+        ))
+        .unwrap();
+        let list = super::migrate(4, &bytes).unwrap();
+        assert_eq!(list.rating_scale, RatingScale::Ten);
+        assert_eq!(
+            list.items,
+            vec![
+                ListItem {
+                    id: 1,
+                    musical_id: 5,
+                    status: Status::Seen,
+                    rating: 7,
+                    review: Some("Twice!".to_string()),
+                    viewings: vec![Viewing {
+                        date: Some("2024-01-20".to_string()),
+                        city: Some("London".to_string()),
+                        rating: Some(9),
+                        ..Default::default()
+                    }],
+                    tags: vec![],
+                    custom: None,
+                },
+                ListItem {
+                    id: 2,
+                    musical_id: 6,
+                    status: Status::WantToSee,
+                    rating: 0,
+                    review: None,
+                    viewings: vec![],
+                    tags: vec![],
+                    custom: None,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn migrates_v5() {
+        let no_viewings: Vec<()> = vec![];
+        let item = |id: u64, status: u8, rating: u8| {
+            (
+                id,
+                id + 10,
+                status,
+                rating,
+                None::<&str>,
+                no_viewings.clone(),
+            )
+        };
+        let bytes = rmp_serde::to_vec(&(
+            5u8,
+            "me",
+            5u8,
+            vec![
+                item(1, 0, 0),
+                item(2, 1, 0),
+                item(3, 2, 4),
+                item(4, 3, 2),
+                // unknown statuses are wanted
+                item(5, 9, 0),
+            ],
+        ))
+        .unwrap();
+        let list = super::migrate(5, &bytes).unwrap();
+        assert_eq!(list.rating_scale, RatingScale::Five);
+        assert!(list.tags.is_empty());
+        let statuses: Vec<(u64, Status, u8)> = list
+            .items
+            .iter()
+            .map(|item| (item.musical_id, item.status, item.rating))
+            .collect();
+        assert_eq!(
+            statuses,
+            vec![
+                (11, Status::WantToSee, 0),
+                (12, Status::Booked, 0),
+                (13, Status::Seen, 4),
+                (14, Status::Abandoned, 2),
+                (15, Status::WantToSee, 0),
+            ]
+        );
+        assert!(list
+            .items
+            .iter()
+            .all(|item| item.tags.is_empty() && item.custom.is_none()));
+    }
+
+    #[test]
+    fn clamps_ratings_to_the_scale() {
+        let list = super::upgrade(super::Versioned::V2(super::v2::MusicaList {
+            version: 2,
+            author: "me".to_string(),
+            rating_scale: 5,
+            items: vec![super::v1::ListItem {
+                id: 1,
+                musical_id: 1,
+                viewed: true,
+                rating: 9,
+            }],
+        }));
+        assert_eq!(list.rating_scale, RatingScale::Five);
+        assert_eq!(list.items[0].rating, 5);
+    }
+
+    #[test]
+    fn drops_unknown_tags() {
+        let no_viewings: Vec<()> = vec![];
+        let bytes = rmp_serde::to_vec(&(
+            6u8,
+            "me",
+            10u8,
+            vec!["jukebox"],
+            vec![(
+                1u64,
+                0u64,
+                2u8,
+                8u8,
+                None::<&str>,
+                no_viewings,
+                vec![0u32, 3],
+                Some(("Fringe show", None::<&str>)),
+            )],
+        ))
+        .unwrap();
+        let list = super::migrate(6, &bytes).unwrap();
+        assert_eq!(list.items[0].tags, vec![0]);
+        assert_eq!(
+            list.items[0]
+                .custom
+                .as_ref()
+                .map(|custom| custom.title.as_str()),
+            Some("Fringe show")
+        );
+    }
+
+    #[test]
+    fn rejects_unknown_versions() {
+        assert!(matches!(
+            super::migrate(0, &[]),
+            Err(DecodeError::UnsupportedVersion(0))
+        ));
+        assert!(matches!(
+            super::migrate(u8::MAX, &[]),
+            Err(DecodeError::UnsupportedVersion(u8::MAX))
+        ));
+    }
+}
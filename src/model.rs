@@ -1,34 +1,691 @@
-#[derive(serde::Serialize, serde::Deserialize)]
-struct Query {
-    content: Option<String>,
-    edit: Option<bool>,
-    user: Option<String>,
+use crate::codec::CURRENT_VERSION;
+use std::collections::HashSet;
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Default)]
+pub struct Query {
+    pub content: Option<String>,
+    pub edit: Option<bool>,
+    pub user: Option<String>,
+    /// Show the catalog metadata columns (year, composer...).
+    pub details: Option<bool>,
+    /// Sort of the table, see [`crate::view::View`].
+    pub sort: Option<String>,
+    /// Show only the musicals which were not seen.
+    pub unseen: Option<bool>,
+    /// Show only the musicals with this status, see [`Status::name`].
+    pub status: Option<String>,
+    /// Show only the musicals rated at least this.
+    pub min_rating: Option<u8>,
+    /// Show only the musicals with this tag.
+    pub tag: Option<String>,
+    /// Users whose lists are compared, see [`Query::compared_users`].
+    pub compare: Option<String>,
+    /// Show the leaderboard of the lists of every user.
+    pub group: Option<bool>,
 }
 
-#[derive(Debug, serde::Deserialize, serde::Serialize, Clone)]
+impl Query {
+    /// The query comparing the lists of `users`: their names separated by
+    /// commas, the commas and percent signs of the names escaped.
+    pub fn comparing(users: &[String]) -> Query {
+        let users: Vec<String> = users
+            .iter()
+            .map(|user| user.replace('%', "%25").replace(',', "%2C"))
+            .collect();
+        Query {
+            compare: Some(users.join(",")),
+            ..Default::default()
+        }
+    }
+
+    /// Users whose lists are compared, if any.
+    pub fn compared_users(&self) -> Option<Vec<String>> {
+        let users = self.compare.as_ref()?;
+        Some(
+            users
+                .split(',')
+                .map(|user| user.replace("%2C", ",").replace("%25", "%"))
+                .collect(),
+        )
+    }
+}
+
+#[derive(Debug, serde::Deserialize, serde::Serialize, Clone, PartialEq, Default)]
 pub struct Musical {
     pub id: u64,
-    name: String,
+    pub name: String,
     pub url: String,
+    /// Tells apart musicals sharing the same name, e.g. "1958 film".
+    pub label: Option<String>,
+    pub year: Option<u16>,
+    pub composer: Option<String>,
+    pub lyricist: Option<String>,
+    pub book_writer: Option<String>,
 }
 
-#[derive(Debug, serde::Deserialize, serde::Serialize, Clone)]
-struct ListItem {
-    id: u64,
-    musical_id: u64,
-    viewed: bool,
-    rating: u8,
+impl Musical {
+    /// What identifies a musical across catalog updates: its Wikipedia page,
+    /// plus its title since several musicals can share a page.
+    pub fn key(&self) -> (&str, &str) {
+        (&self.url, &self.name)
+    }
+
+    /// Name shown to users, with the label when there is one.
+    pub fn display_name(&self) -> String {
+        match &self.label {
+            Some(label) => format!("{} ({})", self.name, label),
+            None => self.name.clone(),
+        }
+    }
 }
 
-#[derive(Debug, serde::Deserialize, serde::Serialize, Clone)]
-struct MusicaList {
-    version: u8,
-    author: String,
-    items: Vec<ListItem>,
+/// Scale of the ratings of a list, serialized as its largest rating.
+#[derive(Debug, serde::Deserialize, serde::Serialize, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(into = "u8", try_from = "u8")]
+pub enum RatingScale {
+    /// Whole stars, from 0 to 5.
+    Five,
+    /// Half stars, from 0 to 10.
+    #[default]
+    Ten,
+    /// Tenths of stars, from 0 to 100.
+    Hundred,
+}
+
+impl RatingScale {
+    pub const ALL: [RatingScale; 3] = [RatingScale::Five, RatingScale::Ten, RatingScale::Hundred];
+
+    pub fn max(self) -> u8 {
+        match self {
+            RatingScale::Five => 5,
+            RatingScale::Ten => 10,
+            RatingScale::Hundred => 100,
+        }
+    }
+
+    /// `rating` brought back within the scale.
+    pub fn clamp(self, rating: u8) -> u8 {
+        rating.min(self.max())
+    }
+
+    /// Rating set by clicking the `star`th star (from 1 to 5) of a widget
+    /// showing `rating`: that many stars, or half a star less when that's
+    /// already the rating (a whole star on the 5 scale) so that half stars
+    /// and 0 can be reached too.
+    pub fn click_star(self, rating: u8, star: u8) -> u8 {
+        let stars = RatingScale::Five.convert(star.min(5), self);
+        if rating == stars {
+            stars - self.max().div_ceil(10)
+        } else {
+            stars
+        }
+    }
+
+    /// `rating` on this scale converted to `to`, rounded to the nearest.
+    pub fn convert(self, rating: u8, to: RatingScale) -> u8 {
+        let (rating, from, to) = (rating as u32, self.max() as u32, to.max() as u32);
+        ((rating * to + from / 2) / from).min(to) as u8
+    }
+
+    /// Five stars filled according to `rating`, rounded to the half star.
+    pub fn stars(self, rating: u8) -> String {
+        let halves = self.convert(rating, RatingScale::Ten) as usize;
+        let full = "★".repeat(halves / 2);
+        let half = if halves % 2 == 1 { "½" } else { "" };
+        let empty = "☆".repeat(5 - halves.div_ceil(2));
+        format!("{}{}{}", full, half, empty)
+    }
+
+    /// `rating` out of the max, e.g. "7/10".
+    pub fn format(self, rating: u8) -> String {
+        format!("{}/{}", rating, self.max())
+    }
+}
+
+impl From<RatingScale> for u8 {
+    fn from(scale: RatingScale) -> u8 {
+        scale.max()
+    }
+}
+
+impl TryFrom<u8> for RatingScale {
+    type Error = String;
+
+    fn try_from(max: u8) -> Result<RatingScale, String> {
+        RatingScale::ALL
+            .into_iter()
+            .find(|scale| scale.max() == max)
+            .ok_or_else(|| format!("no rating scale up to {}", max))
+    }
+}
+
+/// Where a musical is at for the author of the list, serialized as a number.
+#[derive(Debug, serde::Deserialize, serde::Serialize, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(into = "u8", try_from = "u8")]
+pub enum Status {
+    #[default]
+    WantToSee,
+    Booked,
+    Seen,
+    Abandoned,
+}
+
+impl Status {
+    pub const ALL: [Status; 4] = [
+        Status::WantToSee,
+        Status::Booked,
+        Status::Seen,
+        Status::Abandoned,
+    ];
+
+    /// Name of the status in queries.
+    pub fn name(self) -> &'static str {
+        match self {
+            Status::WantToSee => "want-to-see",
+            Status::Booked => "booked",
+            Status::Seen => "seen",
+            Status::Abandoned => "abandoned",
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Status::WantToSee => "want to see",
+            Status::Booked => "booked",
+            Status::Seen => "seen",
+            Status::Abandoned => "abandoned",
+        }
+    }
+
+    pub fn icon(self) -> &'static str {
+        match self {
+            Status::WantToSee => "🔖",
+            Status::Booked => "🎟",
+            Status::Seen => "👁",
+            Status::Abandoned => "🚪",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Status> {
+        Status::ALL.into_iter().find(|status| status.name() == name)
+    }
+}
+
+impl From<Status> for u8 {
+    fn from(status: Status) -> u8 {
+        status as u8
+    }
+}
+
+impl TryFrom<u8> for Status {
+    type Error = String;
+
+    fn try_from(value: u8) -> Result<Status, String> {
+        Status::ALL
+            .get(value as usize)
+            .copied()
+            .ok_or_else(|| format!("no status {}", value))
+    }
+}
+
+/// One time a musical was seen, all optional.
+#[derive(Debug, serde::Deserialize, serde::Serialize, Clone, PartialEq, Default)]
+pub struct Viewing {
+    /// `YYYY-MM-DD`, as given by date inputs.
+    pub date: Option<String>,
+    pub theatre: Option<String>,
+    pub city: Option<String>,
+    pub cast: Option<String>,
+    /// On the scale of the list.
+    pub rating: Option<u8>,
+}
+
+/// Musical missing from the catalog, typed in by the author of the list.
+#[derive(Debug, serde::Deserialize, serde::Serialize, Clone, PartialEq, Default)]
+pub struct CustomMusical {
+    pub title: String,
+    /// Any page about the musical.
+    pub url: Option<String>,
+}
+
+#[derive(Debug, serde::Deserialize, serde::Serialize, Clone, PartialEq, Default)]
+pub struct ListItem {
+    pub id: u64,
+    /// Unused when the musical is `custom`.
+    pub musical_id: u64,
+    pub status: Status,
+    pub rating: u8,
+    pub review: Option<String>,
+    pub viewings: Vec<Viewing>,
+    /// Indices of the tags of the item in [`MusicaList::tags`].
+    pub tags: Vec<u32>,
+    pub custom: Option<CustomMusical>,
+}
+
+impl ListItem {
+    /// The catalog musical of the item, none for custom musicals.
+    pub fn musical<'a>(&self, musicals: &'a [Musical]) -> Option<&'a Musical> {
+        match self.custom {
+            Some(_) => None,
+            None => musicals.iter().find(|m| m.id == self.musical_id),
+        }
+    }
+
+    pub fn display_name(&self, musicals: &[Musical]) -> String {
+        match &self.custom {
+            Some(custom) => custom.title.clone(),
+            None => self
+                .musical(musicals)
+                .map(|m| m.display_name())
+                .unwrap_or_default(),
+        }
+    }
+
+    /// Rating shown for the item: the average of its rated viewings, if any,
+    /// else its own rating.
+    pub fn aggregate_rating(&self) -> u8 {
+        let ratings: Vec<u32> = self
+            .viewings
+            .iter()
+            .filter_map(|viewing| viewing.rating)
+            .map(u32::from)
+            .collect();
+        if ratings.is_empty() {
+            self.rating
+        } else {
+            let len = ratings.len() as u32;
+            ((ratings.iter().sum::<u32>() + len / 2) / len) as u8
+        }
+    }
+
+    pub fn has_notes(&self) -> bool {
+        self.review.is_some() || !self.viewings.is_empty()
+    }
+}
+
+#[derive(Debug, serde::Deserialize, serde::Serialize, Clone, PartialEq)]
+pub struct MusicaList {
+    pub version: u8,
+    pub author: String,
+    pub rating_scale: RatingScale,
+    /// Names of the tags of the items, which only store their indices so
+    /// that a tag used many times takes little room in links.
+    pub tags: Vec<String>,
+    pub items: Vec<ListItem>,
+}
+
+/// An empty list of the current version.
+impl Default for MusicaList {
+    fn default() -> MusicaList {
+        MusicaList {
+            version: CURRENT_VERSION,
+            author: String::new(),
+            rating_scale: RatingScale::default(),
+            tags: vec![],
+            items: vec![],
+        }
+    }
+}
+
+impl MusicaList {
+    /// Id for a new item: one more than the largest id in use, so that it
+    /// never collides with a remaining item, whatever was deleted.
+    pub fn next_item_id(&self) -> u64 {
+        self.items.iter().map(|item| item.id).max().unwrap_or(0) + 1
+    }
+
+    /// Give a new id to the items sharing their id with an earlier item, as
+    /// created by the add button of older versions after a delete. Returns
+    /// whether any item was re-ided.
+    pub fn repair_ids(&mut self) -> bool {
+        let mut seen = HashSet::new();
+        let mut next_id = self.next_item_id();
+        let mut repaired = false;
+        for item in self.items.iter_mut() {
+            if !seen.insert(item.id) {
+                item.id = next_id;
+                next_id += 1;
+                repaired = true;
+            }
+        }
+        repaired
+    }
+
+    /// Move the list to another rating scale, converting its ratings.
+    pub fn set_rating_scale(&mut self, scale: RatingScale) {
+        for item in self.items.iter_mut() {
+            item.rating = self.rating_scale.convert(item.rating, scale);
+            for viewing in item.viewings.iter_mut() {
+                viewing.rating = viewing
+                    .rating
+                    .map(|rating| self.rating_scale.convert(rating, scale));
+            }
+        }
+        self.rating_scale = scale;
+    }
+
+    /// The list with item `id` moved to position `to` (from 0), or to the
+    /// end when `to` is past it. Unchanged when there is no such item.
+    pub fn moved(&self, id: u64, to: usize) -> MusicaList {
+        let mut items = self.items.clone();
+        if let Some(from) = items.iter().position(|item| item.id == id) {
+            let item = items.remove(from);
+            items.insert(to.min(items.len()), item);
+        }
+        MusicaList {
+            items,
+            ..self.clone()
+        }
+    }
+
+    /// Names of the tags of `item`.
+    pub fn item_tags<'a>(&'a self, item: &'a ListItem) -> impl Iterator<Item = &'a str> {
+        item.tags
+            .iter()
+            .filter_map(|tag| self.tags.get(*tag as usize))
+            .map(String::as_str)
+    }
+
+    /// Tag item `id` with `tag`, reusing the tag of the list spelled the same
+    /// regardless of case.
+    pub fn add_tag(&mut self, id: u64, tag: &str) {
+        let tag = tag.trim();
+        if tag.is_empty() {
+            return;
+        }
+        let index = match self
+            .tags
+            .iter()
+            .position(|name| name.to_lowercase() == tag.to_lowercase())
+        {
+            Some(index) => index as u32,
+            None => {
+                self.tags.push(tag.to_string());
+                (self.tags.len() - 1) as u32
+            }
+        };
+        if let Some(item) = self.items.iter_mut().find(|item| item.id == id) {
+            if !item.tags.contains(&index) {
+                item.tags.push(index);
+            }
+        }
+        self.prune_tags();
+    }
+
+    /// Remove the tag at `index` from item `id`.
+    pub fn remove_tag(&mut self, id: u64, index: u32) {
+        if let Some(item) = self.items.iter_mut().find(|item| item.id == id) {
+            item.tags.retain(|tag| *tag != index);
+        }
+        self.prune_tags();
+    }
+
+    /// Forget the tags which no item has anymore, renumbering the others.
+    pub fn prune_tags(&mut self) {
+        let used: HashSet<u32> = self
+            .items
+            .iter()
+            .flat_map(|item| item.tags.iter().copied())
+            .collect();
+        let mut renumbered = vec![None; self.tags.len()];
+        let mut tags = vec![];
+        for (index, tag) in self.tags.drain(..).enumerate() {
+            if used.contains(&(index as u32)) {
+                renumbered[index] = Some(tags.len() as u32);
+                tags.push(tag);
+            }
+        }
+        self.tags = tags;
+        for item in self.items.iter_mut() {
+            item.tags = item
+                .tags
+                .iter()
+                .filter_map(|tag| renumbered.get(*tag as usize).copied().flatten())
+                .collect();
+        }
+    }
+
+    /// Ids of the catalog musicals listed more than once.
+    pub fn duplicate_musicals(&self) -> HashSet<u64> {
+        let mut seen = HashSet::new();
+        self.items
+            .iter()
+            .filter(|item| item.custom.is_none())
+            .filter(|item| !seen.insert(item.musical_id))
+            .map(|item| item.musical_id)
+            .collect()
+    }
 }
 
 #[derive(Debug, serde::Deserialize, serde::Serialize, Clone)]
-struct Users {
-    version: u8,
-    items: Vec<String>,
+pub struct Users {
+    pub version: u8,
+    pub items: Vec<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn compares_users_with_commas_in_their_names() {
+        let users = vec![
+            "Smith, Ana".to_string(),
+            "100%2C".to_string(),
+            "Bo".to_string(),
+        ];
+        let query = Query::comparing(&users);
+        assert_eq!(query.compare.as_deref(), Some("Smith%2C Ana,100%252C,Bo"));
+        assert_eq!(query.compared_users(), Some(users));
+        assert_eq!(Query::default().compared_users(), None);
+    }
+
+    fn item(id: u64, musical_id: u64) -> ListItem {
+        ListItem {
+            id,
+            musical_id,
+            ..Default::default()
+        }
+    }
+
+    fn list(items: Vec<ListItem>) -> MusicaList {
+        MusicaList {
+            items,
+            ..Default::default()
+        }
+    }
+
+    fn ids(list: &MusicaList) -> Vec<u64> {
+        list.items.iter().map(|item| item.id).collect()
+    }
+
+    #[test]
+    fn allocates_ids_after_the_largest() {
+        assert_eq!(list(vec![]).next_item_id(), 1);
+        // [1, 2, 3] with 1 deleted: the old `len + 1` gave 3 again
+        assert_eq!(list(vec![item(2, 7), item(3, 8)]).next_item_id(), 4);
+    }
+
+    #[test]
+    fn repairs_colliding_ids() {
+        let mut colliding = list(vec![item(2, 7), item(3, 8), item(3, 9), item(2, 10)]);
+        assert!(colliding.repair_ids());
+        assert_eq!(ids(&colliding), vec![2, 3, 4, 5]);
+        // the first item keeps its id, the others keep their musical
+        let musicals: Vec<u64> = colliding.items.iter().map(|i| i.musical_id).collect();
+        assert_eq!(musicals, vec![7, 8, 9, 10]);
+    }
+
+    #[test]
+    fn leaves_distinct_ids_alone() {
+        let mut distinct = list(vec![item(5, 7), item(1, 8)]);
+        assert!(!distinct.repair_ids());
+        assert_eq!(ids(&distinct), vec![5, 1]);
+    }
+
+    #[test]
+    fn finds_duplicate_musicals() {
+        let list = list(vec![
+            item(1, 5),
+            item(2, 7),
+            item(3, 5),
+            item(4, 9),
+            item(5, 5),
+        ]);
+        assert_eq!(list.duplicate_musicals(), HashSet::from([5]));
+    }
+
+    #[test]
+    fn custom_musicals_are_not_duplicates() {
+        let custom = |id| ListItem {
+            custom: Some(CustomMusical {
+                title: format!("Fringe show {}", id),
+                url: None,
+            }),
+            ..item(id, 0)
+        };
+        let list = list(vec![custom(1), custom(2), item(3, 5)]);
+        assert!(list.duplicate_musicals().is_empty());
+        assert_eq!(list.items[0].display_name(&[]), "Fringe show 1");
+        assert_eq!(list.items[0].musical(&[Musical::default()]), None);
+    }
+
+    #[test]
+    fn shares_tags_between_items() {
+        let mut tagged = list(vec![item(1, 5), item(2, 7)]);
+        tagged.add_tag(1, "Sondheim");
+        tagged.add_tag(2, " sondheim ");
+        tagged.add_tag(2, "jukebox");
+        tagged.add_tag(2, "jukebox");
+        tagged.add_tag(2, "  ");
+        assert_eq!(tagged.tags, vec!["Sondheim", "jukebox"]);
+        assert_eq!(tagged.items[0].tags, vec![0]);
+        assert_eq!(tagged.items[1].tags, vec![0, 1]);
+        let names: Vec<&str> = tagged.item_tags(&tagged.items[1]).collect();
+        assert_eq!(names, vec!["Sondheim", "jukebox"]);
+    }
+
+    #[test]
+    fn forgets_unused_tags() {
+        let mut tagged = list(vec![item(1, 5), item(2, 7)]);
+        tagged.add_tag(1, "Sondheim");
+        tagged.add_tag(2, "jukebox");
+        tagged.add_tag(2, "saw with Ana");
+        tagged.remove_tag(1, 0);
+        assert_eq!(tagged.tags, vec!["jukebox", "saw with Ana"]);
+        assert_eq!(tagged.items[1].tags, vec![0, 1]);
+        tagged.items.remove(1);
+        tagged.prune_tags();
+        assert!(tagged.tags.is_empty());
+    }
+
+    #[test]
+    fn aggregates_viewing_ratings() {
+        let viewing = |rating| Viewing {
+            rating,
+            ..Default::default()
+        };
+        let mut rated = ListItem {
+            rating: 4,
+            ..item(1, 1)
+        };
+        assert_eq!(rated.aggregate_rating(), 4);
+        rated.viewings = vec![viewing(None)];
+        assert_eq!(rated.aggregate_rating(), 4);
+        rated.viewings = vec![viewing(Some(7)), viewing(None), viewing(Some(10))];
+        assert_eq!(rated.aggregate_rating(), 9);
+        rated.viewings = vec![viewing(Some(7)), viewing(Some(8)), viewing(Some(8))];
+        assert_eq!(rated.aggregate_rating(), 8);
+    }
+
+    #[test]
+    fn moves_items() {
+        let items = list((1..=5).map(|id| item(id, id)).collect());
+        assert_eq!(ids(&items.moved(4, 0)), vec![4, 1, 2, 3, 5]);
+        assert_eq!(ids(&items.moved(1, 4)), vec![2, 3, 4, 5, 1]);
+        assert_eq!(ids(&items.moved(2, 3)), vec![1, 3, 4, 2, 5]);
+        assert_eq!(ids(&items.moved(3, 2)), vec![1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn moves_items_past_the_end_to_the_end() {
+        let items = list((1..=3).map(|id| item(id, id)).collect());
+        assert_eq!(ids(&items.moved(1, usize::MAX)), vec![2, 3, 1]);
+    }
+
+    #[test]
+    fn moves_nothing_for_unknown_items() {
+        let items = list((1..=3).map(|id| item(id, id)).collect());
+        assert_eq!(items.moved(7, 0), items);
+    }
+
+    #[test]
+    fn ratings_do_not_wrap_around() {
+        assert_eq!(RatingScale::Ten.clamp(11), 10);
+        assert_eq!(RatingScale::Ten.clamp(7), 7);
+        assert_eq!(RatingScale::Five.clamp(u8::MAX), 5);
+        assert_eq!(RatingScale::Hundred.clamp(100), 100);
+    }
+
+    #[test]
+    fn clicks_stars() {
+        assert_eq!(RatingScale::Ten.click_star(0, 4), 8);
+        assert_eq!(RatingScale::Ten.click_star(8, 4), 7);
+        assert_eq!(RatingScale::Ten.click_star(2, 1), 1);
+        assert_eq!(RatingScale::Ten.click_star(1, 1), 2);
+        assert_eq!(RatingScale::Five.click_star(3, 3), 2);
+        assert_eq!(RatingScale::Five.click_star(1, 1), 0);
+        assert_eq!(RatingScale::Hundred.click_star(42, 5), 100);
+        assert_eq!(RatingScale::Hundred.click_star(100, 5), 90);
+    }
+
+    #[test]
+    fn converts_ratings() {
+        assert_eq!(RatingScale::Ten.convert(7, RatingScale::Hundred), 70);
+        assert_eq!(RatingScale::Hundred.convert(74, RatingScale::Ten), 7);
+        assert_eq!(RatingScale::Hundred.convert(75, RatingScale::Ten), 8);
+        assert_eq!(RatingScale::Ten.convert(7, RatingScale::Five), 4);
+        assert_eq!(RatingScale::Five.convert(5, RatingScale::Ten), 10);
+    }
+
+    #[test]
+    fn rescales_lists() {
+        let mut rated = list(vec![ListItem {
+            rating: 7,
+            ..item(1, 5)
+        }]);
+        rated.set_rating_scale(RatingScale::Hundred);
+        assert_eq!(rated.rating_scale, RatingScale::Hundred);
+        assert_eq!(rated.items[0].rating, 70);
+    }
+
+    #[test]
+    fn draws_stars() {
+        assert_eq!(RatingScale::Ten.stars(0), "☆☆☆☆☆");
+        assert_eq!(RatingScale::Ten.stars(7), "★★★½☆");
+        assert_eq!(RatingScale::Five.stars(5), "★★★★★");
+        assert_eq!(RatingScale::Hundred.stars(42), "★★☆☆☆");
+    }
+
+    #[test]
+    fn serializes_statuses_as_numbers() {
+        for (i, status) in Status::ALL.into_iter().enumerate() {
+            let bytes = rmp_serde::to_vec(&status).unwrap();
+            assert_eq!(bytes, vec![i as u8]);
+            assert_eq!(rmp_serde::from_slice::<Status>(&bytes).unwrap(), status);
+            assert_eq!(Status::from_name(status.name()), Some(status));
+        }
+        assert!(rmp_serde::from_slice::<Status>(&[4]).is_err());
+    }
+
+    #[test]
+    fn serializes_scales_as_their_max() {
+        let bytes = rmp_serde::to_vec(&RatingScale::Hundred).unwrap();
+        assert_eq!(bytes, rmp_serde::to_vec(&100u8).unwrap());
+        assert_eq!(
+            rmp_serde::from_slice::<RatingScale>(&bytes).unwrap(),
+            RatingScale::Hundred
+        );
+        assert!(rmp_serde::from_slice::<RatingScale>(&[7]).is_err());
+    }
 }
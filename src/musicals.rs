@@ -1,7 +1,13 @@
-include!("model.rs");
-pub static MUSICALS: once_cell::sync::Lazy<Vec<Musical>> = once_cell::sync::Lazy::new(|| {
+use crate::model::{MusicaList, Musical};
+use std::collections::HashMap;
+
+pub static MUSICALS: once_cell::sync::Lazy<Vec<Musical>> =
+    once_cell::sync::Lazy::new(|| parse_musicals(include_str!("musicals.csv")));
+
+/// Read a catalog in the `musicals.csv` format: the fields of [`Musical`]
+/// separated by tabs, without headers.
+pub fn parse_musicals(musicals_csv: &str) -> Vec<Musical> {
     use log::info;
-    let musicals_csv = include_str!("musicals.csv");
     let mut musicals: Vec<Musical> = vec![];
     let mut reader = csv::ReaderBuilder::new()
         .delimiter(b'\t')
@@ -14,4 +20,110 @@ pub static MUSICALS: once_cell::sync::Lazy<Vec<Musical>> = once_cell::sync::Lazy
         };
     }
     musicals
-});
+}
+
+/// Musical ids which are no longer in [`MUSICALS`], with the id of the entry
+/// replacing them if any. Ids in there must never be given to another musical.
+pub type Remap = HashMap<u64, Option<u64>>;
+
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+pub struct RemapRecord {
+    pub old_id: u64,
+    pub new_id: Option<u64>,
+}
+
+pub static REMAP: once_cell::sync::Lazy<Remap> =
+    once_cell::sync::Lazy::new(|| parse_remap(include_str!("musical_remap.csv")));
+
+/// Read a remap table in the `musical_remap.csv` format.
+pub fn parse_remap(remap_csv: &str) -> Remap {
+    use log::info;
+    let mut remap = Remap::new();
+    let mut reader = csv::ReaderBuilder::new()
+        .delimiter(b'\t')
+        .from_reader(remap_csv.as_bytes());
+    for record in reader.deserialize::<RemapRecord>() {
+        match record {
+            Ok(record) => {
+                remap.insert(record.old_id, record.new_id);
+            }
+            Err(err) => info!("{:?}", err),
+        };
+    }
+    remap
+}
+
+/// Follow `remap` from `id` to the musical currently standing for it.
+/// Retired ids without replacement are returned as is.
+pub fn remap_id(remap: &Remap, id: u64) -> u64 {
+    let mut id = id;
+    // bounded so that a cycle in the table can't hang the page
+    for _ in 0..remap.len() {
+        match remap.get(&id) {
+            Some(Some(new_id)) => id = *new_id,
+            _ => break,
+        }
+    }
+    id
+}
+
+/// Point the items of `list` at the current ids of their musicals.
+pub fn remap_list(list: &mut MusicaList) {
+    for item in list.items.iter_mut() {
+        item.musical_id = remap_id(&REMAP, item.musical_id);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn ids_and_keys_are_unique() {
+        let mut ids = HashSet::new();
+        let mut keys = HashSet::new();
+        for musical in MUSICALS.iter() {
+            assert!(ids.insert(musical.id), "duplicate id {}", musical.id);
+            assert!(
+                keys.insert(musical.key()),
+                "duplicate key {:?}",
+                musical.key()
+            );
+        }
+    }
+
+    #[test]
+    fn remapped_ids_are_not_reused() {
+        for (old_id, new_id) in REMAP.iter() {
+            assert!(
+                MUSICALS.iter().all(|m| m.id != *old_id),
+                "{} is reused",
+                old_id
+            );
+            if let Some(new_id) = new_id {
+                assert!(
+                    MUSICALS.iter().any(|m| m.id == remap_id(&REMAP, *new_id)),
+                    "{} is remapped to unknown id {}",
+                    old_id,
+                    new_id
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn remap_follows_chains() {
+        let remap = Remap::from([
+            (1, Some(2)),
+            (2, Some(3)),
+            (4, None),
+            (5, Some(6)),
+            (6, Some(5)),
+        ]);
+        assert_eq!(remap_id(&remap, 1), 3);
+        assert_eq!(remap_id(&remap, 3), 3);
+        assert_eq!(remap_id(&remap, 4), 4);
+        remap_id(&remap, 5);
+    }
+}
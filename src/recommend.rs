@@ -0,0 +1,280 @@
+//! Suggestions of musicals for a list, from the lists of other users: the
+//! musicals rated like the ones it liked (item-based collaborative filtering
+//! with adjusted cosine similarities), then the musicals sharing a composer
+//! or a decade with the ones it liked, from the catalog.
+//!
+//! Only catalog musicals are suggested and taken into account.
+use crate::model::{MusicaList, Musical, RatingScale, Status};
+use std::collections::{HashMap, HashSet};
+
+/// Why a musical is suggested.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Reason {
+    /// Rated like this musical of the list by other users.
+    SimilarTo(u64),
+    Composer(String),
+    /// Decade of the musical, e.g. 1980.
+    Era(u16),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Recommendation {
+    pub musical_id: u64,
+    pub reason: Reason,
+}
+
+/// Ratings out of 100 of the catalog musicals seen in `list`, centered on
+/// their average so that lists rating high or low compare.
+fn centered_ratings(list: &MusicaList) -> HashMap<u64, f64> {
+    let mut ratings = HashMap::new();
+    for item in list.items.iter() {
+        if item.custom.is_none() && item.status == Status::Seen {
+            let rating = list
+                .rating_scale
+                .convert(item.aggregate_rating(), RatingScale::Hundred);
+            ratings.entry(item.musical_id).or_insert(rating as f64);
+        }
+    }
+    let mean = ratings.values().sum::<f64>() / ratings.len().max(1) as f64;
+    for rating in ratings.values_mut() {
+        *rating -= mean;
+    }
+    ratings
+}
+
+/// Adjusted cosine similarity of musicals `a` and `b` over the lists which
+/// have seen both.
+fn similarity(others: &[HashMap<u64, f64>], a: u64, b: u64) -> f64 {
+    let (mut product, mut norm_a, mut norm_b) = (0.0, 0.0, 0.0);
+    for ratings in others {
+        if let (Some(ra), Some(rb)) = (ratings.get(&a), ratings.get(&b)) {
+            product += ra * rb;
+            norm_a += ra * ra;
+            norm_b += rb * rb;
+        }
+    }
+    if norm_a == 0.0 || norm_b == 0.0 {
+        0.0
+    } else {
+        product / (norm_a * norm_b).sqrt()
+    }
+}
+
+/// At most `limit` musicals which are not in `list` yet, best first.
+pub fn recommend(
+    list: &MusicaList,
+    others: &[MusicaList],
+    musicals: &[Musical],
+    limit: usize,
+) -> Vec<Recommendation> {
+    let listed: HashSet<u64> = list
+        .items
+        .iter()
+        .filter(|item| item.custom.is_none())
+        .map(|item| item.musical_id)
+        .collect();
+    let mine = centered_ratings(list);
+    let others: Vec<HashMap<u64, f64>> = others.iter().map(centered_ratings).collect();
+
+    let mut candidates: Vec<u64> = others
+        .iter()
+        .flat_map(|ratings| ratings.keys().copied())
+        .filter(|id| !listed.contains(id))
+        .collect::<HashSet<u64>>()
+        .into_iter()
+        .collect();
+    candidates.sort_unstable();
+    let mut scored: Vec<(f64, Recommendation)> = candidates
+        .into_iter()
+        .filter_map(|candidate| {
+            // what each musical of the list says about the candidate
+            let contributions: Vec<(bool, f64, u64)> = mine
+                .iter()
+                .map(|(id, rating)| {
+                    let contribution = similarity(&others, *id, candidate) * rating;
+                    (*rating > 0.0, contribution, *id)
+                })
+                .collect();
+            let score: f64 = contributions.iter().map(|(_, c, _)| c).sum();
+            // suggested because of a liked musical rather than a disliked one
+            let (_, _, because) = contributions
+                .into_iter()
+                .max_by(|a, b| a.0.cmp(&b.0).then(a.1.total_cmp(&b.1)).then(b.2.cmp(&a.2)))?;
+            (score > 0.0).then_some((
+                score,
+                Recommendation {
+                    musical_id: candidate,
+                    reason: Reason::SimilarTo(because),
+                },
+            ))
+        })
+        .collect();
+    scored.sort_by(|a, b| b.0.total_cmp(&a.0));
+    let mut recommendations: Vec<Recommendation> = scored
+        .into_iter()
+        .map(|(_, recommendation)| recommendation)
+        .collect();
+
+    // the musicals liked in the list: seen and rated at least as its
+    // average, or every musical of the list when none was seen
+    let catalog = |id: &u64| musicals.iter().find(|m| m.id == *id);
+    let mut liked: Vec<&Musical> = list
+        .items
+        .iter()
+        .filter(|item| {
+            mine.get(&item.musical_id)
+                .is_some_and(|rating| *rating >= 0.0)
+        })
+        .filter_map(|item| catalog(&item.musical_id))
+        .collect();
+    if mine.is_empty() {
+        liked = listed.iter().filter_map(catalog).collect();
+    }
+    let mut composers: HashMap<&str, usize> = HashMap::new();
+    let mut eras: HashMap<u16, usize> = HashMap::new();
+    for musical in liked {
+        if let Some(composer) = &musical.composer {
+            *composers.entry(composer).or_default() += 1;
+        }
+        if let Some(year) = musical.year {
+            *eras.entry(year / 10 * 10).or_default() += 1;
+        }
+    }
+    let mut composers: Vec<(&str, usize)> = composers.into_iter().collect();
+    composers.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(b.0)));
+    let mut eras: Vec<(u16, usize)> = eras.into_iter().collect();
+    eras.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+    let fallbacks = composers
+        .into_iter()
+        .flat_map(|(composer, _)| {
+            musicals
+                .iter()
+                .filter(move |m| m.composer.as_deref() == Some(composer))
+                .map(move |m| (m.id, Reason::Composer(composer.to_string())))
+        })
+        .chain(eras.into_iter().flat_map(|(era, _)| {
+            musicals
+                .iter()
+                .filter(move |m| m.year.is_some_and(|year| year / 10 * 10 == era))
+                .map(move |m| (m.id, Reason::Era(era)))
+        }));
+    for (musical_id, reason) in fallbacks {
+        if recommendations.len() >= limit {
+            break;
+        }
+        if !listed.contains(&musical_id)
+            && !recommendations.iter().any(|r| r.musical_id == musical_id)
+        {
+            recommendations.push(Recommendation { musical_id, reason });
+        }
+    }
+    recommendations.truncate(limit);
+    recommendations
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::ListItem;
+
+    fn item(musical_id: u64, status: Status, rating: u8) -> ListItem {
+        ListItem {
+            id: musical_id,
+            musical_id,
+            status,
+            rating,
+            ..Default::default()
+        }
+    }
+
+    fn seen(ratings: &[(u64, u8)]) -> MusicaList {
+        MusicaList {
+            rating_scale: RatingScale::Hundred,
+            items: ratings
+                .iter()
+                .map(|(id, rating)| item(*id, Status::Seen, *rating))
+                .collect(),
+            ..Default::default()
+        }
+    }
+
+    fn musical(id: u64, composer: &str, year: u16) -> Musical {
+        Musical {
+            id,
+            name: format!("Musical {}", id),
+            composer: Some(composer.to_string()),
+            year: Some(year),
+            ..Default::default()
+        }
+    }
+
+    fn musicals() -> Vec<Musical> {
+        vec![
+            musical(1, "Sondheim", 1979),
+            musical(2, "Lloyd Webber", 1986),
+            musical(3, "Schwartz", 2003),
+            musical(4, "Kander", 1966),
+            musical(5, "Sondheim", 1984),
+            musical(6, "Menken", 1975),
+        ]
+    }
+
+    fn musical_ids(recommendations: &[Recommendation]) -> Vec<u64> {
+        recommendations.iter().map(|r| r.musical_id).collect()
+    }
+
+    #[test]
+    fn suggests_what_those_with_the_same_taste_liked() {
+        let mine = seen(&[(1, 90), (2, 30)]);
+        let others = vec![
+            seen(&[(1, 90), (2, 30), (3, 90), (4, 20)]),
+            seen(&[(1, 80), (2, 40), (3, 85), (4, 30)]),
+        ];
+        let recommendations = recommend(&mine, &others, &[], 5);
+        assert_eq!(
+            recommendations,
+            vec![Recommendation {
+                musical_id: 3,
+                reason: Reason::SimilarTo(1),
+            }]
+        );
+    }
+
+    #[test]
+    fn never_suggests_listed_musicals() {
+        let mut mine = seen(&[(1, 90), (2, 30)]);
+        mine.items.push(item(3, Status::WantToSee, 0));
+        let others = vec![seen(&[(1, 90), (2, 30), (3, 90)])];
+        assert!(recommend(&mine, &others, &[], 5).is_empty());
+    }
+
+    #[test]
+    fn falls_back_on_composers_then_eras() {
+        let mine = seen(&[(1, 90), (2, 30)]);
+        let recommendations = recommend(&mine, &[], &musicals(), 5);
+        assert_eq!(musical_ids(&recommendations), vec![5, 6]);
+        assert_eq!(
+            recommendations[0].reason,
+            Reason::Composer("Sondheim".to_string())
+        );
+        assert_eq!(recommendations[1].reason, Reason::Era(1970));
+        assert_eq!(musical_ids(&recommend(&mine, &[], &musicals(), 1)), vec![5]);
+    }
+
+    #[test]
+    fn falls_back_on_wanted_musicals_when_none_was_seen() {
+        let mut mine = seen(&[]);
+        mine.items.push(item(4, Status::WantToSee, 0));
+        let recommendations = recommend(&mine, &[], &musicals(), 5);
+        assert!(recommendations.is_empty());
+        mine.items.push(item(6, Status::Booked, 0));
+        let recommendations = recommend(&mine, &[], &musicals(), 5);
+        assert_eq!(
+            recommendations,
+            vec![Recommendation {
+                musical_id: 1,
+                reason: Reason::Era(1970),
+            }]
+        );
+    }
+}
@@ -0,0 +1,230 @@
+//! Fuzzy search of musicals by name, used by the musical picker.
+//!
+//! Besides its name, a musical can be found by its alias: the title of its
+//! Wikipedia page when it differs (e.g. "8 Women" for "8 femmes").
+use crate::model::Musical;
+use crate::musicals::MUSICALS;
+
+pub static SEARCH_INDEX: once_cell::sync::Lazy<SearchIndex> =
+    once_cell::sync::Lazy::new(|| SearchIndex::new(&MUSICALS));
+
+struct Entry {
+    id: u64,
+    /// Normalized name followed by the normalized aliases.
+    terms: Vec<String>,
+}
+
+pub struct SearchIndex {
+    entries: Vec<Entry>,
+}
+
+/// Lowercase `text`, strip its accents and turn punctuation into spaces so
+/// that "Les Misérables" is found with "les miserables".
+pub fn normalize(text: &str) -> String {
+    let text: String = text
+        .to_lowercase()
+        .chars()
+        .map(|c| match c {
+            'à' | 'á' | 'â' | 'ã' | 'ä' | 'å' => 'a',
+            'ç' => 'c',
+            'è' | 'é' | 'ê' | 'ë' => 'e',
+            'ì' | 'í' | 'î' | 'ï' => 'i',
+            'ñ' => 'n',
+            'ò' | 'ó' | 'ô' | 'õ' | 'ö' | 'ø' => 'o',
+            'ù' | 'ú' | 'û' | 'ü' => 'u',
+            'ý' | 'ÿ' => 'y',
+            '\'' | '’' => '\0',
+            c if c.is_alphanumeric() => c,
+            _ => ' ',
+        })
+        .filter(|c| *c != '\0')
+        .collect();
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Title of a Wikipedia page without its disambiguation.
+fn page_title(url: &str) -> &str {
+    match url.rfind(" (") {
+        Some(start) if url.ends_with(')') => &url[..start],
+        _ => url,
+    }
+}
+
+/// Smallest score of [`SearchIndex::search_scored`] for a musical whose name
+/// or alias is the query.
+pub const EXACT_SCORE: u32 = 1999;
+
+/// How well `query` matches `term`, both normalized: higher is better.
+fn score(term: &str, query: &str) -> Option<u32> {
+    if term == query {
+        Some(1000)
+    } else if term.starts_with(query) {
+        Some(900)
+    } else if term.split(' ').any(|word| word.starts_with(query)) {
+        Some(800)
+    } else if term.contains(query) {
+        Some(700)
+    } else if query
+        .split(' ')
+        .all(|query| term.split(' ').any(|word| word.starts_with(query)))
+    {
+        Some(600)
+    } else {
+        // every character of the query in order, e.g. "jcs" for
+        // "jesus christ superstar", penalized by the gaps between them
+        let mut gaps = 0;
+        let mut chars = term.chars();
+        for q in query.chars().filter(|c| *c != ' ') {
+            let mut gap = 0;
+            loop {
+                match chars.next() {
+                    Some(c) if c == q => break,
+                    Some(_) => gap += 1,
+                    None => return None,
+                }
+            }
+            gaps += gap;
+        }
+        Some(500u32.saturating_sub(gaps))
+    }
+}
+
+impl SearchIndex {
+    pub fn new(musicals: &[Musical]) -> SearchIndex {
+        SearchIndex {
+            entries: musicals
+                .iter()
+                .map(|musical| {
+                    let mut terms = vec![normalize(&musical.name)];
+                    let alias = normalize(page_title(&musical.url));
+                    if !terms.contains(&alias) {
+                        terms.push(alias);
+                    }
+                    Entry {
+                        id: musical.id,
+                        terms,
+                    }
+                })
+                .collect(),
+        }
+    }
+
+    /// Ids of the (at most `limit`) musicals matching `query`, best first.
+    pub fn search(&self, query: &str, limit: usize) -> Vec<u64> {
+        self.search_scored(query, limit)
+            .into_iter()
+            .map(|(_, id)| id)
+            .collect()
+    }
+
+    /// Like [`SearchIndex::search`], with the score of each musical.
+    pub fn search_scored(&self, query: &str, limit: usize) -> Vec<(u32, u64)> {
+        let query = normalize(query);
+        if query.is_empty() {
+            return vec![];
+        }
+        let mut matches: Vec<(u32, usize, u64)> = self
+            .entries
+            .iter()
+            .filter_map(|entry| {
+                entry
+                    .terms
+                    .iter()
+                    .enumerate()
+                    // a match on the name beats the same match on an alias
+                    .filter_map(|(i, term)| {
+                        score(term, &query).map(|score| score * 2 - i.min(1) as u32)
+                    })
+                    .max()
+                    .map(|score| (score, entry.terms[0].len(), entry.id))
+            })
+            .collect();
+        // best score, then shortest name
+        matches.sort_by(|a, b| b.0.cmp(&a.0).then(a.1.cmp(&b.1)));
+        matches
+            .into_iter()
+            .take(limit)
+            .map(|(score, _, id)| (score, id))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn names(ids: Vec<u64>) -> Vec<String> {
+        ids.iter()
+            .map(|id| {
+                MUSICALS
+                    .iter()
+                    .find(|m| m.id == *id)
+                    .unwrap()
+                    .display_name()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn normalizes() {
+        assert_eq!(normalize("Les Misérables"), "les miserables");
+        assert_eq!(normalize("  Ain't  Misbehavin' "), "aint misbehavin");
+        assert_eq!(
+            normalize("1789: Les Amants de la Bastille"),
+            "1789 les amants de la bastille"
+        );
+    }
+
+    #[test]
+    fn finds_exact_names_first() {
+        assert_eq!(names(SEARCH_INDEX.search("cats", 1)), vec!["Cats"]);
+        assert_eq!(
+            names(SEARCH_INDEX.search("42nd street", 2)),
+            vec!["42nd Street (film)", "42nd Street (musical)"]
+        );
+    }
+
+    #[test]
+    fn finds_without_accents_or_punctuation() {
+        assert_eq!(
+            names(SEARCH_INDEX.search("les miserables", 1)),
+            vec!["Les Misérables"]
+        );
+        assert_eq!(
+            names(SEARCH_INDEX.search("aint misbehavin", 1)),
+            vec!["Ain't Misbehavin'"]
+        );
+    }
+
+    #[test]
+    fn finds_by_word_prefixes_and_initials() {
+        assert_eq!(
+            names(SEARCH_INDEX.search("jesus sup", 1)),
+            vec!["Jesus Christ Superstar"]
+        );
+        assert_eq!(
+            names(SEARCH_INDEX.search("jsuperstar", 1)),
+            vec!["Jesus Christ Superstar"]
+        );
+    }
+
+    #[test]
+    fn finds_by_alias() {
+        assert_eq!(names(SEARCH_INDEX.search("8 women", 1)), vec!["8 femmes"]);
+    }
+
+    #[test]
+    fn scores_exact_names_and_aliases() {
+        let exact = |query| SEARCH_INDEX.search_scored(query, 1)[0].0 >= EXACT_SCORE;
+        assert!(exact("Cats"));
+        assert!(exact("8 women"));
+        assert!(!exact("cat"));
+    }
+
+    #[test]
+    fn limits_results() {
+        assert_eq!(SEARCH_INDEX.search("a", 10).len(), 10);
+        assert!(SEARCH_INDEX.search("", 10).is_empty());
+        assert!(SEARCH_INDEX.search("zzzzzzzzzz", 10).is_empty());
+    }
+}
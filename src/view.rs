@@ -0,0 +1,366 @@
+//! Sorting and filtering of the table of a list. Views only change what is
+//! shown: the stored order of the items is left untouched. They are kept in
+//! the query so that a sorted or filtered list can be shared.
+use crate::model::{ListItem, MusicaList, Musical, Query, Status};
+use crate::search::normalize;
+use std::cmp::Ordering;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SortKey {
+    Name,
+    Rating,
+    Status,
+    Year,
+}
+
+impl SortKey {
+    pub const ALL: [SortKey; 4] = [
+        SortKey::Name,
+        SortKey::Rating,
+        SortKey::Status,
+        SortKey::Year,
+    ];
+
+    /// Name of the key in the query.
+    pub fn name(self) -> &'static str {
+        match self {
+            SortKey::Name => "name",
+            SortKey::Rating => "rating",
+            SortKey::Status => "status",
+            SortKey::Year => "year",
+        }
+    }
+
+    fn compare(self, a: &ListItem, b: &ListItem, musicals: &[Musical]) -> Ordering {
+        match self {
+            SortKey::Name => {
+                let name = |item: &ListItem| normalize(&item.display_name(musicals));
+                name(a).cmp(&name(b))
+            }
+            SortKey::Rating => a.aggregate_rating().cmp(&b.aggregate_rating()),
+            SortKey::Status => u8::from(a.status).cmp(&u8::from(b.status)),
+            // musicals without a year last
+            SortKey::Year => {
+                let year = |item: &ListItem| {
+                    item.musical(musicals)
+                        .and_then(|m| m.year)
+                        .unwrap_or(u16::MAX)
+                };
+                year(a).cmp(&year(b))
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct View {
+    pub sort: Option<SortKey>,
+    pub descending: bool,
+    pub unseen_only: bool,
+    pub status: Option<Status>,
+    /// Smallest rating shown, on the scale of the list.
+    pub min_rating: Option<u8>,
+    pub tag: Option<String>,
+}
+
+/// `value` percent-encoded to be a query parameter.
+fn encode_param(value: &str) -> String {
+    value
+        .bytes()
+        .map(|byte| match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                (byte as char).to_string()
+            }
+            _ => format!("%{:02X}", byte),
+        })
+        .collect()
+}
+
+impl View {
+    /// The view of `query`, whose sort is a key name, prefixed with `-` for
+    /// a descending sort. Unknown keys are ignored.
+    pub fn from_query(query: &Query) -> View {
+        let sort = query.sort.as_deref().unwrap_or_default();
+        let (descending, sort) = match sort.strip_prefix('-') {
+            Some(sort) => (true, sort),
+            None => (false, sort),
+        };
+        // sorting by status was sorting by viewed before statuses
+        let sort = if sort == "viewed" { "status" } else { sort };
+        View {
+            sort: SortKey::ALL.into_iter().find(|key| key.name() == sort),
+            descending,
+            unseen_only: query.unseen == Some(true),
+            status: query.status.as_deref().and_then(Status::from_name),
+            min_rating: query.min_rating,
+            tag: query.tag.clone(),
+        }
+    }
+
+    /// Sort parameter of the query for this view.
+    pub fn sort_param(&self) -> Option<String> {
+        self.sort
+            .map(|key| format!("{}{}", if self.descending { "-" } else { "" }, key.name()))
+    }
+
+    /// `query` showing this view.
+    pub fn to_query(&self, query: Query) -> Query {
+        Query {
+            sort: self.sort_param(),
+            unseen: self.unseen_only.then_some(true),
+            status: self.status.map(|status| status.name().to_string()),
+            min_rating: self.min_rating,
+            tag: self.tag.clone(),
+            ..query
+        }
+    }
+
+    /// Query parameters of this view, to append to a url, e.g. `&sort=-rating`.
+    pub fn url_params(&self) -> String {
+        let mut params = String::new();
+        if let Some(sort) = self.sort_param() {
+            params += &format!("&sort={}", sort);
+        }
+        if self.unseen_only {
+            params += "&unseen=true";
+        }
+        if let Some(status) = self.status {
+            params += &format!("&status={}", status.name());
+        }
+        if let Some(min_rating) = self.min_rating {
+            params += &format!("&min_rating={}", min_rating);
+        }
+        if let Some(tag) = &self.tag {
+            params += &format!("&tag={}", encode_param(tag));
+        }
+        params
+    }
+
+    /// The view after clicking the header of `key`: sorted by it ascending,
+    /// then descending, then back to the stored order.
+    pub fn toggle_sort(&self, key: SortKey) -> View {
+        let (sort, descending) = match (self.sort, self.descending) {
+            (Some(sort), false) if sort == key => (Some(key), true),
+            (Some(sort), true) if sort == key => (None, false),
+            _ => (Some(key), false),
+        };
+        View {
+            sort,
+            descending,
+            ..self.clone()
+        }
+    }
+
+    /// Items shown by this view, in order, with their index in the list.
+    pub fn apply<'a>(
+        &self,
+        list: &'a MusicaList,
+        musicals: &[Musical],
+    ) -> Vec<(usize, &'a ListItem)> {
+        let mut items: Vec<(usize, &ListItem)> = list
+            .items
+            .iter()
+            .enumerate()
+            .filter(|(_, item)| !(self.unseen_only && item.status == Status::Seen))
+            .filter(|(_, item)| self.status.is_none_or(|status| item.status == status))
+            .filter(|(_, item)| {
+                self.min_rating
+                    .is_none_or(|min| item.aggregate_rating() >= min)
+            })
+            .filter(|(_, item)| {
+                self.tag
+                    .as_ref()
+                    .is_none_or(|tag| list.item_tags(item).any(|name| name == tag))
+            })
+            .collect();
+        if let Some(key) = self.sort {
+            // stable: items which compare equal stay in the stored order
+            items.sort_by(|(_, a), (_, b)| {
+                let ordering = key.compare(a, b, musicals);
+                if self.descending {
+                    ordering.reverse()
+                } else {
+                    ordering
+                }
+            });
+        }
+        items
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::RatingScale;
+
+    fn musical(id: u64, name: &str, year: Option<u16>) -> Musical {
+        Musical {
+            id,
+            name: name.to_string(),
+            year,
+            ..Default::default()
+        }
+    }
+
+    fn musicals() -> Vec<Musical> {
+        vec![
+            musical(1, "Cats", Some(1981)),
+            musical(2, "Les Misérables", Some(1980)),
+            musical(3, "Evita", None),
+        ]
+    }
+
+    fn list() -> MusicaList {
+        let mut list = untagged();
+        list.add_tag(1, "jukebox");
+        list.add_tag(3, "saw with Ana");
+        list.add_tag(3, "jukebox");
+        list
+    }
+
+    fn untagged() -> MusicaList {
+        let item = |id, musical_id, status, rating| ListItem {
+            id,
+            musical_id,
+            status,
+            rating,
+            review: None,
+            viewings: vec![],
+            tags: vec![],
+            custom: None,
+        };
+        MusicaList {
+            version: 2,
+            author: "me".to_string(),
+            rating_scale: RatingScale::Ten,
+            tags: vec![],
+            items: vec![
+                item(1, 1, Status::Seen, 7),
+                item(2, 2, Status::WantToSee, 9),
+                item(3, 3, Status::Abandoned, 7),
+            ],
+        }
+    }
+
+    fn query(sort: Option<&str>, unseen: Option<bool>, min_rating: Option<u8>) -> Query {
+        Query {
+            sort: sort.map(str::to_string),
+            unseen,
+            min_rating,
+            ..Default::default()
+        }
+    }
+
+    fn ids(view: &View) -> Vec<u64> {
+        let list = list();
+        view.apply(&list, &musicals())
+            .into_iter()
+            .map(|(_, item)| item.id)
+            .collect()
+    }
+
+    #[test]
+    fn keeps_the_stored_order_by_default() {
+        assert_eq!(ids(&View::default()), vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn sorts() {
+        let sorted = |sort| ids(&View::from_query(&query(Some(sort), None, None)));
+        assert_eq!(sorted("name"), vec![1, 3, 2]);
+        assert_eq!(sorted("-name"), vec![2, 3, 1]);
+        // ties keep the stored order
+        assert_eq!(sorted("rating"), vec![1, 3, 2]);
+        assert_eq!(sorted("-rating"), vec![2, 1, 3]);
+        assert_eq!(sorted("status"), vec![2, 1, 3]);
+        // links from before statuses
+        assert_eq!(sorted("viewed"), vec![2, 1, 3]);
+        assert_eq!(sorted("year"), vec![2, 1, 3]);
+        assert_eq!(sorted("unknown"), vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn filters() {
+        assert_eq!(
+            ids(&View::from_query(&query(None, Some(true), None))),
+            vec![2, 3]
+        );
+        let abandoned = Query {
+            status: Some("abandoned".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(ids(&View::from_query(&abandoned)), vec![3]);
+        assert_eq!(ids(&View::from_query(&query(None, None, Some(8)))), vec![2]);
+        assert_eq!(
+            ids(&View::from_query(&query(Some("-rating"), None, Some(7)))),
+            vec![2, 1, 3]
+        );
+    }
+
+    #[test]
+    fn filters_tags() {
+        let tagged = |tag: &str| {
+            ids(&View::from_query(&Query {
+                tag: Some(tag.to_string()),
+                ..Default::default()
+            }))
+        };
+        assert_eq!(tagged("jukebox"), vec![1, 3]);
+        assert_eq!(tagged("saw with Ana"), vec![3]);
+        assert!(tagged("unknown").is_empty());
+    }
+
+    #[test]
+    fn returns_list_indices() {
+        let list = list();
+        let view = View::from_query(&query(Some("-rating"), None, None));
+        let indices: Vec<usize> = view
+            .apply(&list, &musicals())
+            .iter()
+            .map(|(i, _)| *i)
+            .collect();
+        assert_eq!(indices, vec![1, 0, 2]);
+    }
+
+    #[test]
+    fn round_trips_through_queries() {
+        let view = View {
+            sort: Some(SortKey::Year),
+            descending: true,
+            unseen_only: true,
+            status: Some(Status::Booked),
+            min_rating: Some(7),
+            tag: Some("saw with Ana".to_string()),
+        };
+        assert_eq!(View::from_query(&view.to_query(Query::default())), view);
+        assert_eq!(
+            View::from_query(&View::default().to_query(Query::default())),
+            View::default()
+        );
+    }
+
+    #[test]
+    fn writes_url_params() {
+        assert_eq!(View::default().url_params(), "");
+        let view = View::from_query(&query(Some("-rating"), Some(true), Some(7)));
+        assert_eq!(view.url_params(), "&sort=-rating&unseen=true&min_rating=7");
+        let tagged = View {
+            tag: Some("saw with Ana & co".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(tagged.url_params(), "&tag=saw%20with%20Ana%20%26%20co");
+    }
+
+    #[test]
+    fn toggles_sorts() {
+        let view = View::default().toggle_sort(SortKey::Rating);
+        assert_eq!(view.sort_param().as_deref(), Some("rating"));
+        let view = view.toggle_sort(SortKey::Rating);
+        assert_eq!(view.sort_param().as_deref(), Some("-rating"));
+        assert_eq!(view.toggle_sort(SortKey::Rating), View::default());
+        assert_eq!(
+            view.toggle_sort(SortKey::Name).sort_param().as_deref(),
+            Some("name")
+        );
+    }
+}